@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#[macro_use]
+extern crate criterion;
+use criterion::Criterion;
+extern crate arrow;
+extern crate datafusion;
+
+use std::sync::Arc;
+
+use arrow::datatypes::{DataType, Field, Schema};
+use datafusion::common::JoinType;
+use datafusion::config::ConfigOptions;
+use datafusion::physical_optimizer::PhysicalOptimizerRule;
+use datafusion::physical_optimizer::ProjectionPushdown;
+use datafusion::physical_plan::expressions::Column;
+use datafusion::physical_plan::joins::{HashJoinExec, PartitionMode};
+use datafusion::physical_plan::memory::MemoryExec;
+use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::{ExecutionPlan, PhysicalExpr};
+
+// Creates an empty scan of table `table` with `width` Int32 columns.
+fn scan(table: usize, width: usize) -> Arc<dyn ExecutionPlan> {
+    let schema = Arc::new(Schema::new(
+        (0..width)
+            .map(|i| Field::new(format!("t{table}_c{i}"), DataType::Int32, true))
+            .collect::<Vec<_>>(),
+    ));
+    Arc::new(MemoryExec::try_new(&[], schema, None).unwrap())
+}
+
+// Creates a left-deep chain of `depth` inner hash joins over scans with
+// `width` columns each, topped with a projection that keeps only the first two
+// columns of the first table and of the last table.
+fn projected_join_chain(depth: usize, width: usize) -> Arc<dyn ExecutionPlan> {
+    let mut plan = scan(0, width);
+    for table in 1..=depth {
+        plan = Arc::new(
+            HashJoinExec::try_new(
+                plan,
+                scan(table, width),
+                vec![(
+                    Arc::new(Column::new("t0_c0", 0)) as _,
+                    Arc::new(Column::new(&format!("t{table}_c0"), 0)) as _,
+                )],
+                None,
+                &JoinType::Inner,
+                PartitionMode::CollectLeft,
+                false,
+            )
+            .unwrap(),
+        );
+    }
+
+    let schema = plan.schema();
+    let last_table_start = width * depth;
+    let exprs: Vec<(Arc<dyn PhysicalExpr>, String)> =
+        [0, 1, last_table_start, last_table_start + 1]
+            .into_iter()
+            .map(|index| {
+                let name = schema.field(index).name();
+                (Arc::new(Column::new(name, index)) as _, name.to_string())
+            })
+            .collect();
+    Arc::new(ProjectionExec::try_new(exprs, plan).unwrap())
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let config = ConfigOptions::new();
+    let benches = vec![
+        // A single join over very wide scans with many unused columns.
+        ("wide_shallow_join", 1, 200),
+        // Many joins over wide scans.
+        ("wide_deep_joins", 16, 120),
+        // Many joins over narrow scans.
+        ("narrow_deep_joins", 64, 4),
+    ];
+
+    for (name, depth, width) in benches {
+        let plan = projected_join_chain(depth, width);
+
+        // The optimized plan must produce the same schema as the original one:
+        let optimized = ProjectionPushdown::new()
+            .optimize(plan.clone(), &config)
+            .unwrap();
+        assert_eq!(optimized.schema(), plan.schema());
+
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                ProjectionPushdown::new()
+                    .optimize(plan.clone(), &config)
+                    .unwrap()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
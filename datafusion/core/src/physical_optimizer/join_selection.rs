@@ -423,6 +423,10 @@ fn statistical_join_selection_subrule(
             let right = cross_join.right();
             if should_swap_join_order(&**left, &**right)? {
                 let new_join = CrossJoinExec::new(Arc::clone(right), Arc::clone(left));
+                let new_join = match cross_join.max_output_batch_bytes() {
+                    Some(bytes) => new_join.with_max_output_batch_bytes(bytes),
+                    None => new_join,
+                };
                 // TODO avoid adding ProjectionExec again and again, only adding Final Projection
                 let proj: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
                     swap_reverting_projection(&left.schema(), &right.schema()),
@@ -885,6 +889,37 @@ mod tests_statistical {
         crosscheck_plans(join.clone()).unwrap();
     }
 
+    #[tokio::test]
+    async fn test_cross_join_with_swap_keeps_batch_budget() {
+        let (big, small) = create_big_and_small();
+
+        let join = Arc::new(
+            CrossJoinExec::new(Arc::clone(&big), Arc::clone(&small))
+                .with_max_output_batch_bytes(4096),
+        );
+
+        let optimized_join = JoinSelection::new()
+            .optimize(join.clone(), &ConfigOptions::new())
+            .unwrap();
+
+        let swapping_projection = optimized_join
+            .as_any()
+            .downcast_ref::<ProjectionExec>()
+            .expect("A proj is required to swap columns back to their original order");
+
+        let swapped_join = swapping_projection
+            .input()
+            .as_any()
+            .downcast_ref::<CrossJoinExec>()
+            .expect("The type of the plan should not be changed");
+
+        assert_eq!(
+            swapped_join.left().statistics().unwrap().total_byte_size,
+            Precision::Inexact(8192)
+        );
+        assert_eq!(swapped_join.max_output_batch_bytes(), Some(4096));
+    }
+
     #[tokio::test]
     async fn test_left_join_with_swap() {
         let (big, small) = create_big_and_small();
@@ -42,3 +42,4 @@ mod utils;
 pub mod test_utils;
 
 pub use optimizer::PhysicalOptimizerRule;
+pub use projection_pushdown::ProjectionPushdown;
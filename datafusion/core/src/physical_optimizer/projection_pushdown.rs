@@ -27,45 +27,64 @@ use super::output_requirements::OutputRequirementExec;
 use super::PhysicalOptimizerRule;
 use crate::datasource::physical_plan::CsvExec;
 use crate::error::Result;
+use crate::physical_plan::coalesce_batches::CoalesceBatchesExec;
 use crate::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+use crate::physical_plan::empty::EmptyExec;
 use crate::physical_plan::filter::FilterExec;
-use crate::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
+use crate::physical_plan::joins::utils::{ColumnIndex, JoinFilter, JoinOn, JoinOnRef};
 use crate::physical_plan::joins::{
     CrossJoinExec, HashJoinExec, NestedLoopJoinExec, SortMergeJoinExec,
     SymmetricHashJoinExec,
 };
+use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use crate::physical_plan::memory::MemoryExec;
+use crate::physical_plan::placeholder_row::PlaceholderRowExec;
 use crate::physical_plan::projection::ProjectionExec;
 use crate::physical_plan::repartition::RepartitionExec;
 use crate::physical_plan::sorts::sort::SortExec;
 use crate::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
+use crate::physical_plan::values::ValuesExec;
 use crate::physical_plan::{Distribution, ExecutionPlan, ExecutionPlanProperties};
 
 use arrow_schema::SchemaRef;
 use datafusion_common::config::ConfigOptions;
+use datafusion_common::stats::Precision;
 use datafusion_common::tree_node::{
     Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
 };
-use datafusion_common::{DataFusionError, JoinSide};
-use datafusion_physical_expr::expressions::{Column, Literal};
+use datafusion_common::{DataFusionError, JoinSide, JoinType};
+use datafusion_physical_expr::expressions::{Column, Literal, UnKnownColumn};
+use datafusion_physical_expr::utils::collect_columns;
 use datafusion_physical_expr::{
     Partitioning, PhysicalExpr, PhysicalExprRef, PhysicalSortExpr,
     PhysicalSortRequirement,
 };
 use datafusion_physical_plan::streaming::StreamingTableExec;
-use datafusion_physical_plan::union::UnionExec;
+use datafusion_physical_plan::union::{can_interleave, InterleaveExec, UnionExec};
 
 use itertools::Itertools;
 
 /// This rule inspects [`ProjectionExec`]'s in the given physical plan and tries to
 /// remove or swap with its child.
 #[derive(Default)]
-pub struct ProjectionPushdown {}
+pub struct ProjectionPushdown {
+    /// If `true`, only redundant projections are removed or unified, and
+    /// projections are never pushed below other operators.
+    removal_only: bool,
+}
 
 impl ProjectionPushdown {
     #[allow(missing_docs)]
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Creates a rule that only removes projections which do not change their
+    /// input and unifies consecutive projections. Unlike [`Self::new`], it keeps
+    /// the rest of the plan in its original shape: projections are neither
+    /// swapped with other operators nor embedded into sources.
+    pub fn removal_only() -> Self {
+        Self { removal_only: true }
     }
 }
 
@@ -75,7 +94,11 @@ impl PhysicalOptimizerRule for ProjectionPushdown {
         plan: Arc<dyn ExecutionPlan>,
         _config: &ConfigOptions,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        plan.transform_down(&remove_unnecessary_projections).data()
+        if self.removal_only {
+            plan.transform_down(&remove_redundant_projections).data()
+        } else {
+            plan.transform_down(&remove_unnecessary_projections).data()
+        }
     }
 
     fn name(&self) -> &str {
@@ -100,7 +123,11 @@ pub fn remove_unnecessary_projections(
         // If the projection does not cause any change on the input, we can
         // safely remove it:
         if is_projection_removable(projection) {
-            return Ok(Transformed::yes(projection.input().clone()));
+            // The input takes the place of the removed projection, so it must
+            // be inspected too (it may be a projection itself):
+            return remove_unnecessary_projections(projection.input().clone())
+                .data()
+                .map(Transformed::yes);
         }
         // If it does, check if we can push it under its child(ren):
         let input = projection.input().as_any();
@@ -111,7 +138,8 @@ pub fn remove_unnecessary_projections(
         } else if let Some(child_projection) = input.downcast_ref::<ProjectionExec>() {
             let maybe_unified = try_unifying_projections(projection, child_projection)?;
             return if let Some(new_plan) = maybe_unified {
-                // To unify 3 or more sequential projections:
+                // To continue with the unified projection, e.g. to partially
+                // unify it with a caching projection below or to push it down:
                 remove_unnecessary_projections(new_plan)
                     .data()
                     .map(Transformed::yes)
@@ -122,6 +150,13 @@ pub fn remove_unnecessary_projections(
             try_swapping_with_output_req(projection, output_req)?
         } else if input.is::<CoalescePartitionsExec>() {
             try_swapping_with_coalesce_partitions(projection)?
+        } else if let Some(coalesce_batches) = input.downcast_ref::<CoalesceBatchesExec>()
+        {
+            try_swapping_with_coalesce_batches(projection, coalesce_batches)?
+        } else if let Some(global_limit) = input.downcast_ref::<GlobalLimitExec>() {
+            try_swapping_with_global_limit(projection, global_limit)?
+        } else if let Some(local_limit) = input.downcast_ref::<LocalLimitExec>() {
+            try_swapping_with_local_limit(projection, local_limit)?
         } else if let Some(filter) = input.downcast_ref::<FilterExec>() {
             try_swapping_with_filter(projection, filter)?
         } else if let Some(repartition) = input.downcast_ref::<RepartitionExec>() {
@@ -132,6 +167,8 @@ pub fn remove_unnecessary_projections(
             try_swapping_with_sort_preserving_merge(projection, spm)?
         } else if let Some(union) = input.downcast_ref::<UnionExec>() {
             try_pushdown_through_union(projection, union)?
+        } else if let Some(interleave) = input.downcast_ref::<InterleaveExec>() {
+            try_pushdown_through_interleave(projection, interleave)?
         } else if let Some(hash_join) = input.downcast_ref::<HashJoinExec>() {
             try_pushdown_through_hash_join(projection, hash_join)?
         } else if let Some(cross_join) = input.downcast_ref::<CrossJoinExec>() {
@@ -144,12 +181,19 @@ pub fn remove_unnecessary_projections(
             try_swapping_with_sym_hash_join(projection, sym_join)?
         } else if let Some(ste) = input.downcast_ref::<StreamingTableExec>() {
             try_swapping_with_streaming_table(projection, ste)?
+        } else if let Some(empty) = input.downcast_ref::<EmptyExec>() {
+            try_swapping_with_empty(projection, empty)
+        } else if let Some(placeholder) = input.downcast_ref::<PlaceholderRowExec>() {
+            try_swapping_with_placeholder_row(projection, placeholder)
+        } else if let Some(values) = input.downcast_ref::<ValuesExec>() {
+            try_swapping_with_values(projection, values)?
         } else {
-            // If the input plan of the projection is not one of the above, we
+            // If the input plan of the projection is not one of the above, it
+            // may still embed the projection itself. Otherwise, we
             // conservatively assume that pushing the projection down may hurt.
             // When adding new operators, consider adding them here if you
             // think pushing projections under them is beneficial.
-            None
+            projection.input().try_pushdown_projection(projection)?
         }
     } else {
         return Ok(Transformed::no(plan));
@@ -158,6 +202,33 @@ pub fn remove_unnecessary_projections(
     Ok(maybe_modified.map_or(Transformed::no(plan), Transformed::yes))
 }
 
+/// This function checks if `plan` is a [`ProjectionExec`], and removes it if it
+/// does not change its input, or unifies it with its input if that is also a
+/// [`ProjectionExec`]. Unlike [`remove_unnecessary_projections`], it never pushes
+/// `plan` below any other operator.
+pub fn remove_redundant_projections(
+    plan: Arc<dyn ExecutionPlan>,
+) -> Result<Transformed<Arc<dyn ExecutionPlan>>> {
+    let Some(projection) = plan.as_any().downcast_ref::<ProjectionExec>() else {
+        return Ok(Transformed::no(plan));
+    };
+    if is_projection_removable(projection) {
+        return remove_redundant_projections(projection.input().clone())
+            .data()
+            .map(Transformed::yes);
+    }
+    if let Some(child_projection) =
+        projection.input().as_any().downcast_ref::<ProjectionExec>()
+    {
+        if let Some(new_plan) = try_unifying_projections(projection, child_projection)? {
+            return remove_redundant_projections(new_plan)
+                .data()
+                .map(Transformed::yes);
+        }
+    }
+    Ok(Transformed::no(plan))
+}
+
 /// Tries to embed `projection` to its input (`csv`). If possible, returns
 /// [`CsvExec`] as the top plan. Otherwise, returns `None`.
 fn try_swapping_with_csv(
@@ -260,16 +331,125 @@ fn try_swapping_with_streaming_table(
     .map(|e| Some(Arc::new(e) as _))
 }
 
+/// Tries to embed `projection` to its input (`empty`). If possible, returns
+/// [`EmptyExec`] with the narrowed schema as the top plan. Otherwise, returns
+/// `None`.
+fn try_swapping_with_empty(
+    projection: &ProjectionExec,
+    empty: &EmptyExec,
+) -> Option<Arc<dyn ExecutionPlan>> {
+    // If there is any non-column or alias-carrier expression, Projection should not be removed.
+    all_alias_free_columns(projection.expr()).then(|| {
+        Arc::new(
+            EmptyExec::new(projection.schema())
+                .with_partitions(empty.output_partitioning().partition_count()),
+        ) as _
+    })
+}
+
+/// Tries to embed `projection` to its input (`placeholder`). If possible,
+/// returns [`PlaceholderRowExec`] with the narrowed schema as the top plan,
+/// which still produces a single row per partition. Otherwise, returns `None`.
+fn try_swapping_with_placeholder_row(
+    projection: &ProjectionExec,
+    placeholder: &PlaceholderRowExec,
+) -> Option<Arc<dyn ExecutionPlan>> {
+    // If there is any non-column or alias-carrier expression, Projection should not be removed.
+    all_alias_free_columns(projection.expr()).then(|| {
+        Arc::new(
+            PlaceholderRowExec::new(projection.schema())
+                .with_partitions(placeholder.output_partitioning().partition_count()),
+        ) as _
+    })
+}
+
+/// Tries to embed `projection` to its input (`values`). If possible, returns
+/// [`ValuesExec`] carrying only the projected columns as the top plan.
+/// Otherwise, returns `None`.
+fn try_swapping_with_values(
+    projection: &ProjectionExec,
+    values: &ValuesExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // If there is any non-column or alias-carrier expression, Projection should not be removed.
+    if !all_alias_free_columns(projection.expr()) {
+        return Ok(None);
+    }
+
+    let all_columns = (0..values.schema().fields().len()).collect::<Vec<_>>();
+    let new_projections = new_projections_for_columns(projection, &all_columns);
+    let new_schema = Arc::new(values.schema().project(&new_projections)?);
+    let new_batches = values
+        .data()
+        .iter()
+        .map(|batch| batch.project(&new_projections))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    ValuesExec::try_new_from_batches(new_schema, new_batches)
+        .map(|e| Some(Arc::new(e) as _))
+}
+
 /// Unifies `projection` with its input (which is also a [`ProjectionExec`]).
+/// The maximal run of consecutive projections below `projection` that can be
+/// fully unified is collapsed at once, so that only a single [`ProjectionExec`]
+/// is constructed for the whole run.
 fn try_unifying_projections(
     projection: &ProjectionExec,
     child: &ProjectionExec,
 ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
-    let mut projected_exprs = vec![];
+    let mut projected_exprs = projection.expr().to_vec();
+    let mut input = projection.input().clone();
+    let mut unified_count = 0;
+
+    loop {
+        let current = input.clone();
+        let Some(current_child) = current.as_any().downcast_ref::<ProjectionExec>()
+        else {
+            break;
+        };
+
+        // Merging these projections is not beneficial, e.g
+        // If an expression is not trivial and it is referred more than 1, unifies projections will be
+        // beneficial as caching mechanism for non-trivial computations.
+        // See discussion in: https://github.com/apache/arrow-datafusion/issues/8296
+        let cached_indices = cached_expr_indices(&projected_exprs, current_child);
+        if !cached_indices.is_empty() {
+            if unified_count == 0 {
+                // Such expressions stay in the child projection, but the
+                // remaining references can still be unified:
+                return try_partially_unifying_projections(
+                    projection,
+                    child,
+                    &cached_indices,
+                );
+            }
+            // The unified projection is handled separately at this boundary:
+            break;
+        }
+
+        let Some(unified_exprs) = unify_exprs(&projected_exprs, current_child)? else {
+            break;
+        };
+        projected_exprs = unified_exprs;
+        input = current_child.input().clone();
+        unified_count += 1;
+    }
+
+    if unified_count == 0 {
+        return Ok(None);
+    }
+    ProjectionExec::try_new(projected_exprs, input).map(|e| Some(Arc::new(e) as _))
+}
+
+/// Returns the indices of the non-trivial expressions of `child` that are
+/// referred more than once by `exprs`, in ascending order.
+fn cached_expr_indices(
+    exprs: &[(Arc<dyn PhysicalExpr>, String)],
+    child: &ProjectionExec,
+) -> Vec<usize> {
     let mut column_ref_map: HashMap<Column, usize> = HashMap::new();
 
     // Collect the column references usage in the outer projection.
-    projection.expr().iter().for_each(|(expr, _)| {
+    exprs.iter().for_each(|(expr, _)| {
         expr.apply(&mut |expr| {
             Ok({
                 if let Some(column) = expr.as_any().downcast_ref::<Column>() {
@@ -281,17 +461,25 @@ fn try_unifying_projections(
         .unwrap();
     });
 
-    // Merging these projections is not beneficial, e.g
-    // If an expression is not trivial and it is referred more than 1, unifies projections will be
-    // beneficial as caching mechanism for non-trivial computations.
-    // See discussion in: https://github.com/apache/arrow-datafusion/issues/8296
-    if column_ref_map.iter().any(|(column, count)| {
-        *count > 1 && !is_expr_trivial(&child.expr()[column.index()].0.clone())
-    }) {
-        return Ok(None);
-    }
+    column_ref_map
+        .iter()
+        .filter(|(column, count)| {
+            **count > 1 && !is_expr_trivial(&child.expr()[column.index()].0.clone())
+        })
+        .map(|(column, _)| column.index())
+        .sorted()
+        .dedup()
+        .collect_vec()
+}
 
-    for (expr, alias) in projection.expr() {
+/// Rewrites `exprs`, which refer to the output of `child`, in terms of the
+/// input of `child`. Returns `None` if any expression can not be rewritten.
+fn unify_exprs(
+    exprs: &[(Arc<dyn PhysicalExpr>, String)],
+    child: &ProjectionExec,
+) -> Result<Option<Vec<(Arc<dyn PhysicalExpr>, String)>>> {
+    let mut projected_exprs = vec![];
+    for (expr, alias) in exprs {
         // If there is no match in the input projection, we cannot unify these
         // projections. This case will arise if the projection expression contains
         // a `PhysicalExpr` variant `update_expr` doesn't support.
@@ -300,8 +488,84 @@ fn try_unifying_projections(
         };
         projected_exprs.push((expr, alias.clone()));
     }
+    Ok(Some(projected_exprs))
+}
+
+/// Unifies `projection` with its input `child` projection, except for the
+/// expressions of `child` at `cached_indices`. These are non-trivial expressions
+/// referred more than once, so they stay in the child projection to be computed
+/// only once. Returns `None` if this does not narrow the child projection.
+fn try_partially_unifying_projections(
+    projection: &ProjectionExec,
+    child: &ProjectionExec,
+    cached_indices: &[usize],
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // The new child projection starts with the cached expressions, and it is
+    // extended with the input columns referred by the unified expressions.
+    let mut child_exprs = cached_indices
+        .iter()
+        .map(|index| child.expr()[*index].clone())
+        .collect_vec();
+    let mut input_column_map: HashMap<usize, usize> = HashMap::new();
+
+    let mut projected_exprs = vec![];
+    for (expr, alias) in projection.expr() {
+        let new_expr = expr
+            .clone()
+            .transform_up_mut(&mut |expr: Arc<dyn PhysicalExpr>| {
+                let Some(column) = expr.as_any().downcast_ref::<Column>() else {
+                    return Ok(Transformed::no(expr));
+                };
+                let (child_expr, child_alias) = &child.expr()[column.index()];
+                if let Some(position) =
+                    cached_indices.iter().position(|idx| *idx == column.index())
+                {
+                    return Ok(Transformed::yes(Arc::new(Column::new(
+                        child_alias,
+                        position,
+                    )) as _));
+                }
+                // Inline the child expression, and point its columns to the
+                // pass-through columns of the new child projection:
+                child_expr
+                    .clone()
+                    .transform_up_mut(&mut |expr: Arc<dyn PhysicalExpr>| {
+                        let Some(input_column) = expr.as_any().downcast_ref::<Column>()
+                        else {
+                            return Ok(Transformed::no(expr));
+                        };
+                        let new_index = *input_column_map
+                            .entry(input_column.index())
+                            .or_insert_with(|| {
+                                child_exprs.push((
+                                    expr.clone(),
+                                    input_column.name().to_string(),
+                                ));
+                                child_exprs.len() - 1
+                            });
+                        Ok(Transformed::yes(Arc::new(Column::new(
+                            input_column.name(),
+                            new_index,
+                        )) as _))
+                    })
+                    .data()
+                    .map(Transformed::yes)
+            })
+            .data()?;
+        projected_exprs.push((new_expr, alias.clone()));
+    }
+
+    if child_exprs.len() >= child.expr().len() {
+        return Ok(None);
+    }
+    // Pass-through columns are named after the input columns, which must not
+    // collide with the aliases of the cached expressions:
+    if !child_exprs.iter().map(|(_, name)| name).all_unique() {
+        return Ok(None);
+    }
 
-    ProjectionExec::try_new(projected_exprs, child.input().clone())
+    let new_child = ProjectionExec::try_new(child_exprs, child.input().clone())?;
+    ProjectionExec::try_new(projected_exprs, Arc::new(new_child))
         .map(|e| Some(Arc::new(e) as _))
 }
 
@@ -378,6 +642,62 @@ fn try_swapping_with_coalesce_partitions(
         .map(|e| Some(Arc::new(CoalescePartitionsExec::new(e)) as _))
 }
 
+/// Tries to swap `projection` with its input (`coalesce_batches`). If possible,
+/// performs the swap and returns [`CoalesceBatchesExec`] as the top plan.
+/// Otherwise, returns `None`.
+fn try_swapping_with_coalesce_batches(
+    projection: &ProjectionExec,
+    coalesce_batches: &CoalesceBatchesExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // If the projection does not narrow the the schema, we should not try to push it down:
+    if projection.expr().len() >= projection.input().schema().fields().len() {
+        return Ok(None);
+    }
+    make_with_child(projection, coalesce_batches.input()).map(|e| {
+        Some(Arc::new(CoalesceBatchesExec::new(
+            e,
+            coalesce_batches.target_batch_size(),
+        )) as _)
+    })
+}
+
+/// Tries to swap `projection` with its input (`global_limit`). If possible,
+/// performs the swap and returns [`GlobalLimitExec`] as the top plan.
+/// Otherwise, returns `None`.
+fn try_swapping_with_global_limit(
+    projection: &ProjectionExec,
+    global_limit: &GlobalLimitExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // If the projection does not narrow the the schema, we should not try to push it down:
+    if projection.expr().len() >= projection.input().schema().fields().len() {
+        return Ok(None);
+    }
+
+    make_with_child(projection, global_limit.input()).map(|e| {
+        Some(Arc::new(GlobalLimitExec::new(
+            e,
+            global_limit.skip(),
+            global_limit.fetch(),
+        )) as _)
+    })
+}
+
+/// Tries to swap `projection` with its input (`local_limit`). If possible,
+/// performs the swap and returns [`LocalLimitExec`] as the top plan.
+/// Otherwise, returns `None`.
+fn try_swapping_with_local_limit(
+    projection: &ProjectionExec,
+    local_limit: &LocalLimitExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // If the projection does not narrow the the schema, we should not try to push it down:
+    if projection.expr().len() >= projection.input().schema().fields().len() {
+        return Ok(None);
+    }
+
+    make_with_child(projection, local_limit.input())
+        .map(|e| Some(Arc::new(LocalLimitExec::new(e, local_limit.fetch())) as _))
+}
+
 /// Tries to swap `projection` with its input (`filter`). If possible, performs
 /// the swap and returns [`FilterExec`] as the top plan. Otherwise, returns `None`.
 fn try_swapping_with_filter(
@@ -389,19 +709,98 @@ fn try_swapping_with_filter(
         return Ok(None);
     }
     // Each column in the predicate expression must exist after the projection.
-    let Some(new_predicate) = update_expr(filter.predicate(), projection.expr(), false)?
+    if let Some(new_predicate) =
+        update_expr(filter.predicate(), projection.expr(), false)?
+    {
+        return FilterExec::try_new(
+            new_predicate,
+            make_with_child(projection, filter.input())?,
+        )
+        .and_then(|e| {
+            let selectivity = filter.default_selectivity();
+            e.with_default_selectivity(selectivity)
+        })
+        .map(|e| Some(Arc::new(e) as _));
+    }
+
+    // Otherwise, the predicate needs columns that the projection discards. We
+    // can still narrow the input of the filter down to the columns used by
+    // either of them, and keep the projection on top of the filter:
+    let Some(narrowing) =
+        new_narrowing_projection(projection, filter.input(), &[filter.predicate()])?
+    else {
+        return Ok(None);
+    };
+    let Some(new_predicate) = update_expr(filter.predicate(), narrowing.expr(), false)?
+    else {
+        return Ok(None);
+    };
+    let Some(new_projection_exprs) =
+        update_projection_exprs(projection.expr(), narrowing.expr())?
     else {
         return Ok(None);
     };
 
-    FilterExec::try_new(new_predicate, make_with_child(projection, filter.input())?)
-        .and_then(|e| {
+    let new_filter =
+        FilterExec::try_new(new_predicate, Arc::new(narrowing)).and_then(|e| {
             let selectivity = filter.default_selectivity();
             e.with_default_selectivity(selectivity)
-        })
+        })?;
+    ProjectionExec::try_new(new_projection_exprs, Arc::new(new_filter))
         .map(|e| Some(Arc::new(e) as _))
 }
 
+/// Creates a [`ProjectionExec`] on top of `input` that keeps only the columns
+/// used by either `projection` (whose input has the same schema as `input`) or
+/// `exprs`, in their original order. Returns `None` if this projection would
+/// not narrow the schema of `input`.
+fn new_narrowing_projection(
+    projection: &ProjectionExec,
+    input: &Arc<dyn ExecutionPlan>,
+    exprs: &[&Arc<dyn PhysicalExpr>],
+) -> Result<Option<ProjectionExec>> {
+    let input_schema = input.schema();
+    let indices = projection
+        .expr()
+        .iter()
+        .map(|(expr, _)| expr)
+        .chain(exprs.iter().copied())
+        .flat_map(collect_columns)
+        .map(|col| col.index())
+        .sorted()
+        .dedup()
+        .collect_vec();
+    if indices.len() >= input_schema.fields().len() {
+        return Ok(None);
+    }
+
+    let narrowing_exprs = indices
+        .into_iter()
+        .map(|index| {
+            let name = input_schema.field(index).name();
+            (Arc::new(Column::new(name, index)) as _, name.clone())
+        })
+        .collect();
+    ProjectionExec::try_new(narrowing_exprs, input.clone()).map(Some)
+}
+
+/// Rewrites the expressions of a projection, which refer to the input of
+/// `projected_exprs`, so that they refer to the output of `projected_exprs`.
+/// Returns `None` if any of them cannot be rewritten.
+fn update_projection_exprs(
+    exprs: &[(Arc<dyn PhysicalExpr>, String)],
+    projected_exprs: &[(Arc<dyn PhysicalExpr>, String)],
+) -> Result<Option<Vec<(Arc<dyn PhysicalExpr>, String)>>> {
+    let mut updated_exprs = vec![];
+    for (expr, alias) in exprs {
+        let Some(new_expr) = update_expr(expr, projected_exprs, false)? else {
+            return Ok(None);
+        };
+        updated_exprs.push((new_expr, alias.clone()));
+    }
+    Ok(Some(updated_exprs))
+}
+
 /// Tries to swap the projection with its input [`RepartitionExec`]. If it can be done,
 /// it returns the new swapped version having the [`RepartitionExec`] as the top plan.
 /// Otherwise, it returns None.
@@ -438,10 +837,12 @@ fn try_swapping_with_repartition(
         others => others.clone(),
     };
 
-    Ok(Some(Arc::new(RepartitionExec::try_new(
-        new_projection,
-        new_partitioning,
-    )?)))
+    let mut new_repartition = RepartitionExec::try_new(new_projection, new_partitioning)?;
+    if repartition.preserve_order() {
+        new_repartition = new_repartition.with_preserve_order();
+    }
+
+    Ok(Some(Arc::new(new_repartition)))
 }
 
 /// Tries to swap the projection with its input [`SortExec`]. If it can be done,
@@ -456,9 +857,49 @@ fn try_swapping_with_sort(
         return Ok(None);
     }
 
+    if let Some(updated_exprs) = update_sort_exprs(sort.expr(), projection.expr())? {
+        return Ok(Some(Arc::new(
+            SortExec::new(updated_exprs, make_with_child(projection, sort.input())?)
+                .with_fetch(sort.fetch())
+                .with_preserve_partitioning(sort.preserve_partitioning()),
+        )));
+    }
+
+    // Otherwise, the sort keys need columns that the projection discards. We
+    // can still narrow the input of the sort down to the columns used by
+    // either of them, and keep the projection on top of the sort:
+    let sort_exprs = sort.expr().iter().map(|sort| &sort.expr).collect_vec();
+    let Some(narrowing) =
+        new_narrowing_projection(projection, sort.input(), &sort_exprs)?
+    else {
+        return Ok(None);
+    };
+    let Some(updated_exprs) = update_sort_exprs(sort.expr(), narrowing.expr())? else {
+        return Ok(None);
+    };
+    let Some(new_projection_exprs) =
+        update_projection_exprs(projection.expr(), narrowing.expr())?
+    else {
+        return Ok(None);
+    };
+
+    let new_sort = SortExec::new(updated_exprs, Arc::new(narrowing))
+        .with_fetch(sort.fetch())
+        .with_preserve_partitioning(sort.preserve_partitioning());
+    ProjectionExec::try_new(new_projection_exprs, Arc::new(new_sort))
+        .map(|e| Some(Arc::new(e) as _))
+}
+
+/// Rewrites the given sort expressions, which refer to the input of
+/// `projected_exprs`, so that they refer to the output of `projected_exprs`.
+/// Returns `None` if any of them cannot be rewritten.
+fn update_sort_exprs(
+    sort_exprs: &[PhysicalSortExpr],
+    projected_exprs: &[(Arc<dyn PhysicalExpr>, String)],
+) -> Result<Option<Vec<PhysicalSortExpr>>> {
     let mut updated_exprs = vec![];
-    for sort in sort.expr() {
-        let Some(new_expr) = update_expr(&sort.expr, projection.expr(), false)? else {
+    for sort in sort_exprs {
+        let Some(new_expr) = update_expr(&sort.expr, projected_exprs, false)? else {
             return Ok(None);
         };
         updated_exprs.push(PhysicalSortExpr {
@@ -466,12 +907,7 @@ fn try_swapping_with_sort(
             options: sort.options,
         });
     }
-
-    Ok(Some(Arc::new(
-        SortExec::new(updated_exprs, make_with_child(projection, sort.input())?)
-            .with_fetch(sort.fetch())
-            .with_preserve_partitioning(sort.preserve_partitioning()),
-    )))
+    Ok(Some(updated_exprs))
 }
 
 /// Tries to swap the projection with its input [`SortPreservingMergeExec`].
@@ -528,6 +964,33 @@ fn try_pushdown_through_union(
     Ok(Some(Arc::new(UnionExec::new(new_children))))
 }
 
+/// Tries to push `projection` down through `interleave`. If possible, performs
+/// the pushdown and returns a new [`InterleaveExec`] as the top plan which has
+/// projections as its children. Otherwise, returns `None`.
+fn try_pushdown_through_interleave(
+    projection: &ProjectionExec,
+    interleave: &InterleaveExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // If the projection doesn't narrow the schema, we shouldn't try to push it down.
+    if projection.expr().len() >= projection.input().schema().fields().len() {
+        return Ok(None);
+    }
+
+    let new_children = interleave
+        .children()
+        .into_iter()
+        .map(|child| make_with_child(projection, &child))
+        .collect::<Result<Vec<_>>>()?;
+
+    // The new children must still share the same hash partitioning, remapped
+    // through the projection; otherwise, they can not be interleaved anymore.
+    if !can_interleave(new_children.iter()) {
+        return Ok(None);
+    }
+
+    Ok(Some(Arc::new(InterleaveExec::try_new(new_children)?)))
+}
+
 /// Tries to push `projection` down through `hash_join`. If possible, performs the
 /// pushdown and returns a new [`HashJoinExec`] as the top plan which has projections
 /// as its children. Otherwise, returns `None`.
@@ -535,6 +998,18 @@ fn try_pushdown_through_hash_join(
     projection: &ProjectionExec,
     hash_join: &HashJoinExec,
 ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // Semi and anti joins only output the columns of one side, so they are
+    // handled separately:
+    if matches!(
+        hash_join.join_type(),
+        JoinType::LeftSemi
+            | JoinType::LeftAnti
+            | JoinType::RightSemi
+            | JoinType::RightAnti
+    ) {
+        return try_pushdown_through_semi_anti_hash_join(projection, hash_join);
+    }
+
     // Convert projected expressions to columns. We can not proceed if this is
     // not possible.
     let Some(projection_as_columns) = physical_to_column_exprs(projection.expr()) else {
@@ -597,6 +1072,36 @@ fn try_pushdown_through_hash_join(
     )?)))
 }
 
+/// Tries to push `projection` down through a semi or anti [`HashJoinExec`],
+/// see [`try_pushdown_through_semi_anti_join`].
+fn try_pushdown_through_semi_anti_hash_join(
+    projection: &ProjectionExec,
+    hash_join: &HashJoinExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let Some(pushed) = try_pushdown_through_semi_anti_join(
+        projection,
+        hash_join.join_type(),
+        hash_join.left(),
+        hash_join.right(),
+        hash_join.on(),
+        hash_join.filter(),
+    )?
+    else {
+        return Ok(None);
+    };
+
+    let new_join = Arc::new(HashJoinExec::try_new(
+        pushed.left,
+        pushed.right,
+        pushed.on,
+        pushed.filter,
+        hash_join.join_type(),
+        *hash_join.partition_mode(),
+        hash_join.null_equals_null,
+    )?);
+    with_remaining_projection(new_join, pushed.remaining_exprs)
+}
+
 /// Tries to swap the projection with its input [`CrossJoinExec`]. If it can be done,
 /// it returns the new swapped version having the [`CrossJoinExec`] as the top plan.
 /// Otherwise, it returns None.
@@ -620,7 +1125,7 @@ fn try_swapping_with_cross_join(
         far_right_left_col_ind,
         far_left_right_col_ind,
     ) {
-        return Ok(None);
+        return try_narrowing_cross_join_children(cross_join, &projection_as_columns);
     }
 
     let (new_left, new_right) = new_join_children(
@@ -631,42 +1136,191 @@ fn try_swapping_with_cross_join(
         cross_join.right(),
     )?;
 
-    Ok(Some(Arc::new(CrossJoinExec::new(
+    Ok(Some(Arc::new(new_cross_join(
+        cross_join,
         Arc::new(new_left),
         Arc::new(new_right),
     ))))
 }
 
-/// Tries to swap the projection with its input [`NestedLoopJoinExec`]. If it can be done,
-/// it returns the new swapped version having the [`NestedLoopJoinExec`] as the top plan.
-/// Otherwise, it returns None.
-fn try_swapping_with_nested_loop_join(
-    projection: &ProjectionExec,
-    nl_join: &NestedLoopJoinExec,
+/// Handles projections that only refer to the columns of one side of the given
+/// [`CrossJoinExec`]. If the unused side is known to produce exactly one row,
+/// the join is replaced by the used side. Otherwise, the projection can not be
+/// pushed below the join, since the unused side still determines the number of
+/// output rows. Instead, the used side is narrowed to the referenced columns and
+/// the unused side to a single, cheap column. The projection stays on top of the
+/// join with updated indices.
+fn try_narrowing_cross_join_children(
+    cross_join: &CrossJoinExec,
+    projection_as_columns: &[(Column, String)],
 ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
-    // Convert projected PhysicalExpr's to columns. If not possible, we cannot proceed.
-    let Some(projection_as_columns) = physical_to_column_exprs(projection.expr()) else {
-        return Ok(None);
+    let left_size = cross_join.left().schema().fields().len();
+    let uses_left = projection_as_columns
+        .iter()
+        .any(|(col, _)| col.index() < left_size);
+    let uses_right = projection_as_columns
+        .iter()
+        .any(|(col, _)| col.index() >= left_size);
+    let (used, unused, offset) = match (uses_left, uses_right) {
+        (true, false) => (cross_join.left(), cross_join.right(), 0),
+        (false, true) => (cross_join.right(), cross_join.left(), left_size),
+        _ => return Ok(None),
     };
 
-    let (far_right_left_col_ind, far_left_right_col_ind) = join_table_borders(
-        nl_join.left().schema().fields().len(),
-        &projection_as_columns,
+    // Combining every row of the used side with a single row does not change
+    // the rows of the used side, as long as the partitioning stays the same:
+    let single_row_unused = matches!(
+        unused.statistics().map(|stats| stats.num_rows),
+        Ok(Precision::Exact(1))
     );
+    if single_row_unused
+        && used.output_partitioning().partition_count()
+            == cross_join
+                .properties()
+                .output_partitioning()
+                .partition_count()
+    {
+        let new_exprs = projection_as_columns
+            .iter()
+            .map(|(col, alias)| {
+                (
+                    Arc::new(Column::new(col.name(), col.index() - offset)) as _,
+                    alias.clone(),
+                )
+            })
+            .collect_vec();
+        let new_projection = ProjectionExec::try_new(new_exprs, used.clone())?;
+        // The projection may now be removed or pushed into the used side:
+        return remove_unnecessary_projections(Arc::new(new_projection))
+            .data()
+            .map(Some);
+    }
 
-    if !join_allows_pushdown(
-        &projection_as_columns,
-        nl_join.schema(),
-        far_right_left_col_ind,
-        far_left_right_col_ind,
-    ) {
+    let used_schema = used.schema();
+    let used_indices = projection_as_columns
+        .iter()
+        .map(|(col, _)| col.index() - offset)
+        .sorted()
+        .dedup()
+        .collect_vec();
+    let narrow_used = used_indices.len() < used_schema.fields().len();
+
+    let unused_schema = unused.schema();
+    let narrow_unused = unused_schema.fields().len() > 1;
+
+    if !narrow_used && !narrow_unused {
         return Ok(None);
     }
 
-    let new_filter = if let Some(filter) = nl_join.filter() {
-        match update_join_filter(
-            &projection_as_columns[0..=far_right_left_col_ind as _],
-            &projection_as_columns[far_left_right_col_ind as _..],
+    let new_used: Arc<dyn ExecutionPlan> = if narrow_used {
+        let exprs: Vec<(Arc<dyn PhysicalExpr>, String)> = used_indices
+            .iter()
+            .map(|&index| {
+                let name = used_schema.field(index).name();
+                (Arc::new(Column::new(name, index)) as _, name.clone())
+            })
+            .collect_vec();
+        Arc::new(ProjectionExec::try_new(exprs, used.clone())?)
+    } else {
+        used.clone()
+    };
+    let new_unused: Arc<dyn ExecutionPlan> = if narrow_unused {
+        let index = narrowest_column_index(&unused_schema).unwrap();
+        let name = unused_schema.field(index).name();
+        let exprs = vec![(Arc::new(Column::new(name, index)) as _, name.clone())];
+        Arc::new(ProjectionExec::try_new(exprs, unused.clone())?)
+    } else {
+        unused.clone()
+    };
+
+    // Remap the projection onto the output of the narrowed join:
+    let used_offset = if uses_left {
+        0
+    } else {
+        new_unused.schema().fields().len()
+    };
+    let new_exprs = projection_as_columns
+        .iter()
+        .map(|(col, alias)| {
+            let position = used_indices
+                .iter()
+                .position(|&index| index == col.index() - offset)
+                .unwrap();
+            (
+                Arc::new(Column::new(col.name(), used_offset + position)) as _,
+                alias.clone(),
+            )
+        })
+        .collect_vec();
+
+    let new_join = if uses_left {
+        new_cross_join(cross_join, new_used, new_unused)
+    } else {
+        new_cross_join(cross_join, new_unused, new_used)
+    };
+
+    Ok(Some(Arc::new(ProjectionExec::try_new(
+        new_exprs,
+        Arc::new(new_join),
+    )?)))
+}
+
+/// Creates a [`CrossJoinExec`] with the given children that keeps the settings
+/// of `cross_join`.
+fn new_cross_join(
+    cross_join: &CrossJoinExec,
+    left: Arc<dyn ExecutionPlan>,
+    right: Arc<dyn ExecutionPlan>,
+) -> CrossJoinExec {
+    let new_join = CrossJoinExec::new(left, right);
+    match cross_join.max_output_batch_bytes() {
+        Some(bytes) => new_join.with_max_output_batch_bytes(bytes),
+        None => new_join,
+    }
+}
+
+/// Tries to swap the projection with its input [`NestedLoopJoinExec`]. If it can be done,
+/// it returns the new swapped version having the [`NestedLoopJoinExec`] as the top plan.
+/// Otherwise, it returns None.
+fn try_swapping_with_nested_loop_join(
+    projection: &ProjectionExec,
+    nl_join: &NestedLoopJoinExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // Semi and anti joins only output the columns of one side, so they are
+    // handled separately:
+    if matches!(
+        nl_join.join_type(),
+        JoinType::LeftSemi
+            | JoinType::LeftAnti
+            | JoinType::RightSemi
+            | JoinType::RightAnti
+    ) {
+        return try_pushdown_through_semi_anti_nl_join(projection, nl_join);
+    }
+
+    // Convert projected PhysicalExpr's to columns. If not possible, we cannot proceed.
+    let Some(projection_as_columns) = physical_to_column_exprs(projection.expr()) else {
+        return Ok(None);
+    };
+
+    let (far_right_left_col_ind, far_left_right_col_ind) = join_table_borders(
+        nl_join.left().schema().fields().len(),
+        &projection_as_columns,
+    );
+
+    if !join_allows_pushdown(
+        &projection_as_columns,
+        nl_join.schema(),
+        far_right_left_col_ind,
+        far_left_right_col_ind,
+    ) {
+        return Ok(None);
+    }
+
+    let new_filter = if let Some(filter) = nl_join.filter() {
+        match update_join_filter(
+            &projection_as_columns[0..=far_right_left_col_ind as _],
+            &projection_as_columns[far_left_right_col_ind as _..],
             filter,
             nl_join.left(),
             nl_join.right(),
@@ -694,6 +1348,243 @@ fn try_swapping_with_nested_loop_join(
     )?)))
 }
 
+/// Tries to push `projection` down through a semi or anti [`NestedLoopJoinExec`],
+/// see [`try_pushdown_through_semi_anti_join`].
+fn try_pushdown_through_semi_anti_nl_join(
+    projection: &ProjectionExec,
+    nl_join: &NestedLoopJoinExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let Some(pushed) = try_pushdown_through_semi_anti_join(
+        projection,
+        nl_join.join_type(),
+        nl_join.left(),
+        nl_join.right(),
+        &[],
+        nl_join.filter(),
+    )?
+    else {
+        return Ok(None);
+    };
+
+    let new_join = Arc::new(NestedLoopJoinExec::try_new(
+        pushed.left,
+        pushed.right,
+        pushed.filter,
+        nl_join.join_type(),
+    )?);
+    with_remaining_projection(new_join, pushed.remaining_exprs)
+}
+
+/// The children, equi-join conditions and filter of a semi or anti join after
+/// pushing a projection down through it. If the pushed down projection had to
+/// keep extra columns for the join, `remaining_exprs` holds the projection to
+/// put back on top of the join.
+struct SemiAntiJoinPushdown {
+    left: Arc<dyn ExecutionPlan>,
+    right: Arc<dyn ExecutionPlan>,
+    on: JoinOn,
+    filter: Option<JoinFilter>,
+    remaining_exprs: Option<Vec<(Arc<dyn PhysicalExpr>, String)>>,
+}
+
+/// Puts a projection with the given `remaining_exprs`, if any, on top of
+/// `new_join`.
+fn with_remaining_projection(
+    new_join: Arc<dyn ExecutionPlan>,
+    remaining_exprs: Option<Vec<(Arc<dyn PhysicalExpr>, String)>>,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    match remaining_exprs {
+        Some(exprs) => {
+            ProjectionExec::try_new(exprs, new_join).map(|e| Some(Arc::new(e) as _))
+        }
+        None => Ok(Some(new_join)),
+    }
+}
+
+/// Returns the indices of the columns on the given `side` of a join that are
+/// used by its equi-join conditions (`on`) or its `filter`, in ascending order.
+fn join_key_indices(
+    on: JoinOnRef,
+    filter: Option<&JoinFilter>,
+    side: JoinSide,
+) -> Vec<usize> {
+    let on_indices = on.iter().flat_map(|(left_on, right_on)| {
+        let expr = match side {
+            JoinSide::Left => left_on,
+            JoinSide::Right => right_on,
+        };
+        collect_columns(expr).into_iter().map(|col| col.index())
+    });
+    let filter_indices = filter
+        .map(|filter| {
+            filter
+                .column_indices()
+                .iter()
+                .filter(|col_idx| col_idx.side == side)
+                .map(|col_idx| col_idx.index)
+                .collect_vec()
+        })
+        .unwrap_or_default();
+    on_indices
+        .chain(filter_indices)
+        .sorted()
+        .dedup()
+        .collect_vec()
+}
+
+/// Tries to push `projection` down through a semi or anti join with the given
+/// children, equi-join conditions (`on`) and `filter`. Since such a join only
+/// outputs the columns of one side, the projection is pushed to that side, and
+/// the other side is narrowed to the columns referenced by the equi-join
+/// conditions and the join filter. If the projection drops columns of the
+/// output side that the join needs, these columns are kept below the join and
+/// the projection is put back on top of it. If this is not possible, returns
+/// `None`.
+fn try_pushdown_through_semi_anti_join(
+    projection: &ProjectionExec,
+    join_type: &JoinType,
+    left: &Arc<dyn ExecutionPlan>,
+    right: &Arc<dyn ExecutionPlan>,
+    on: JoinOnRef,
+    filter: Option<&JoinFilter>,
+) -> Result<Option<SemiAntiJoinPushdown>> {
+    let output_side = match join_type {
+        JoinType::LeftSemi | JoinType::LeftAnti => JoinSide::Left,
+        _ => JoinSide::Right,
+    };
+    let (output_child, other_child) = match output_side {
+        JoinSide::Left => (left, right),
+        JoinSide::Right => (right, left),
+    };
+
+    // Convert projected PhysicalExpr's to columns. If not possible, we cannot proceed.
+    let Some(projection_as_columns) = physical_to_column_exprs(projection.expr()) else {
+        return Ok(None);
+    };
+    // If the projection does not narrow the schema, we should not try to push it down:
+    if projection_as_columns.len() >= output_child.schema().fields().len() {
+        return Ok(None);
+    }
+
+    // The output side must keep the columns used by the equi-join conditions
+    // and the join filter. If the projection drops any of them, push down a
+    // projection that keeps them, and put the projection back on top instead:
+    let output_key_indices = join_key_indices(on, filter, output_side);
+    let keeps_keys = output_key_indices.iter().all(|index| {
+        projection_as_columns
+            .iter()
+            .any(|(col, _)| col.index() == *index)
+    });
+    let (output_columns, remaining_exprs) = if keeps_keys {
+        (projection_as_columns, None)
+    } else {
+        let output_schema = output_child.schema();
+        let output_columns = projection_as_columns
+            .iter()
+            .map(|(col, _)| col.index())
+            .chain(output_key_indices)
+            .sorted()
+            .dedup()
+            .map(|index| {
+                let name = output_schema.field(index).name();
+                (Column::new(name, index), name.clone())
+            })
+            .collect_vec();
+        if output_columns.len() >= output_schema.fields().len() {
+            return Ok(None);
+        }
+        let Some(remaining_exprs) =
+            update_projection_exprs(projection.expr(), &column_exprs(&output_columns))?
+        else {
+            return Ok(None);
+        };
+        (output_columns, Some(remaining_exprs))
+    };
+
+    // The side whose columns are discarded only needs to provide the columns
+    // used by the equi-join conditions and the join filter. If there are none,
+    // it still determines whether there is a match, so keep a single column:
+    let other_schema = other_child.schema();
+    let mut other_indices = join_key_indices(on, filter, output_side.negate());
+    if other_indices.is_empty() {
+        other_indices.extend(narrowest_column_index(&other_schema));
+    }
+    let other_columns = other_indices
+        .into_iter()
+        .map(|index| {
+            let name = other_schema.field(index).name();
+            (Column::new(name, index), name.clone())
+        })
+        .collect_vec();
+
+    let (left_exprs, right_exprs) = match output_side {
+        JoinSide::Left => (&output_columns, &other_columns),
+        JoinSide::Right => (&other_columns, &output_columns),
+    };
+    let Some(new_on) = update_join_on(left_exprs, right_exprs, on) else {
+        return Ok(None);
+    };
+
+    let new_filter = if let Some(filter) = filter {
+        match update_join_filter(left_exprs, right_exprs, filter, left, right) {
+            Some(updated_filter) => Some(updated_filter),
+            None => return Ok(None),
+        }
+    } else {
+        None
+    };
+
+    let new_output_child: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+        column_exprs(&output_columns),
+        output_child.clone(),
+    )?);
+    // Narrowing the discarded side is only meaningful if it leaves some, but
+    // not all, of its columns:
+    let new_other_child = if other_columns.is_empty()
+        || other_columns.len() == other_schema.fields().len()
+    {
+        other_child.clone()
+    } else {
+        Arc::new(ProjectionExec::try_new(
+            column_exprs(&other_columns),
+            other_child.clone(),
+        )?) as _
+    };
+    let (new_left, new_right) = match output_side {
+        JoinSide::Left => (new_output_child, new_other_child),
+        JoinSide::Right => (new_other_child, new_output_child),
+    };
+
+    Ok(Some(SemiAntiJoinPushdown {
+        left: new_left,
+        right: new_right,
+        on: new_on,
+        filter: new_filter,
+        remaining_exprs,
+    }))
+}
+
+/// Returns the index of the column of `schema` with the smallest fixed width,
+/// or the first column if none of them has a fixed width. Returns `None` if
+/// `schema` has no columns.
+fn narrowest_column_index(schema: &SchemaRef) -> Option<usize> {
+    (0..schema.fields().len()).min_by_key(|&index| {
+        schema
+            .field(index)
+            .data_type()
+            .primitive_width()
+            .unwrap_or(usize::MAX)
+    })
+}
+
+/// Converts the given columns and their aliases into projection expressions.
+fn column_exprs(columns: &[(Column, String)]) -> Vec<(Arc<dyn PhysicalExpr>, String)> {
+    columns
+        .iter()
+        .map(|(col, alias)| (Arc::new(col.clone()) as _, alias.clone()))
+        .collect()
+}
+
 /// Tries to swap the projection with its input [`SortMergeJoinExec`]. If it can be done,
 /// it returns the new swapped version having the [`SortMergeJoinExec`] as the top plan.
 /// Otherwise, it returns None.
@@ -701,6 +1592,18 @@ fn try_swapping_with_sort_merge_join(
     projection: &ProjectionExec,
     sm_join: &SortMergeJoinExec,
 ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // Semi and anti joins only output the columns of one side, which the join
+    // borders computed below do not account for:
+    if matches!(
+        sm_join.join_type(),
+        JoinType::LeftSemi
+            | JoinType::LeftAnti
+            | JoinType::RightSemi
+            | JoinType::RightAnti
+    ) {
+        return Ok(None);
+    }
+
     // Convert projected PhysicalExpr's to columns. If not possible, we cannot proceed.
     let Some(projection_as_columns) = physical_to_column_exprs(projection.expr()) else {
         return Ok(None);
@@ -728,6 +1631,21 @@ fn try_swapping_with_sort_merge_join(
         return Ok(None);
     };
 
+    let new_filter = if let Some(filter) = &sm_join.filter {
+        match update_join_filter(
+            &projection_as_columns[0..=far_right_left_col_ind as _],
+            &projection_as_columns[far_left_right_col_ind as _..],
+            filter,
+            sm_join.left(),
+            sm_join.right(),
+        ) {
+            Some(updated_filter) => Some(updated_filter),
+            None => return Ok(None),
+        }
+    } else {
+        None
+    };
+
     let (new_left, new_right) = new_join_children(
         projection_as_columns,
         far_right_left_col_ind,
@@ -740,7 +1658,7 @@ fn try_swapping_with_sort_merge_join(
         Arc::new(new_left),
         Arc::new(new_right),
         new_on,
-        sm_join.filter.clone(),
+        new_filter,
         sm_join.join_type,
         sm_join.sort_options.clone(),
         sm_join.null_equals_null,
@@ -754,6 +1672,18 @@ fn try_swapping_with_sym_hash_join(
     projection: &ProjectionExec,
     sym_join: &SymmetricHashJoinExec,
 ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    // Semi and anti joins only output the columns of one side, which the join
+    // borders computed below do not account for:
+    if matches!(
+        sym_join.join_type(),
+        JoinType::LeftSemi
+            | JoinType::LeftAnti
+            | JoinType::RightSemi
+            | JoinType::RightAnti
+    ) {
+        return Ok(None);
+    }
+
     // Convert projected PhysicalExpr's to columns. If not possible, we cannot proceed.
     let Some(projection_as_columns) = physical_to_column_exprs(projection.expr()) else {
         return Ok(None);
@@ -796,6 +1726,29 @@ fn try_swapping_with_sym_hash_join(
         None
     };
 
+    // The sort expressions drive the interval-based pruning of the join
+    // state, so they must follow the columns into the new children:
+    let new_left_sort_exprs = match sym_join.left_sort_exprs() {
+        Some(sort_exprs) => match update_join_sort_exprs(
+            &projection_as_columns[0..=far_right_left_col_ind as _],
+            sort_exprs,
+        ) {
+            Some(updated_sort_exprs) => Some(updated_sort_exprs),
+            None => return Ok(None),
+        },
+        None => None,
+    };
+    let new_right_sort_exprs = match sym_join.right_sort_exprs() {
+        Some(sort_exprs) => match update_join_sort_exprs(
+            &projection_as_columns[far_left_right_col_ind as _..],
+            sort_exprs,
+        ) {
+            Some(updated_sort_exprs) => Some(updated_sort_exprs),
+            None => return Ok(None),
+        },
+        None => None,
+    };
+
     let (new_left, new_right) = new_join_children(
         projection_as_columns,
         far_right_left_col_ind,
@@ -811,8 +1764,8 @@ fn try_swapping_with_sym_hash_join(
         new_filter,
         sym_join.join_type(),
         sym_join.null_equals_null(),
-        sym_join.right().output_ordering().map(|p| p.to_vec()),
-        sym_join.left().output_ordering().map(|p| p.to_vec()),
+        new_left_sort_exprs,
+        new_right_sort_exprs,
         sym_join.partition_mode(),
     )?)))
 }
@@ -875,6 +1828,8 @@ fn new_projections_for_columns(
 ///    given the expressions `c@0`, `a@1` and `b@2`, and the [`ProjectionExec`] with
 ///    an output schema of `a, c_new`, then `c@0` becomes `c_new@1`, `a@1` becomes
 ///    `a@0`, but `b@2` results in `None` since the projection does not include `b`.
+///    Expressions containing an [`UnKnownColumn`] also result in `None`, since
+///    there is no way to tell whether the projection keeps what they refer to.
 fn update_expr(
     expr: &Arc<dyn PhysicalExpr>,
     projected_exprs: &[(Arc<dyn PhysicalExpr>, String)],
@@ -900,6 +1855,11 @@ fn update_expr(
                 return Ok(Transformed::no(expr));
             }
 
+            if !sync_with_child && expr.as_any().is::<UnKnownColumn>() {
+                state = RewriteState::RewrittenInvalid;
+                return Ok(Transformed::no(expr));
+            }
+
             let Some(column) = expr.as_any().downcast_ref::<Column>() else {
                 return Ok(Transformed::no(expr));
             };
@@ -1070,6 +2030,29 @@ fn new_columns_for_join_on(
     (new_columns.len() == hash_join_on.len()).then_some(new_columns)
 }
 
+/// Updates the sort expressions of one side of a [`SymmetricHashJoinExec`] as if
+/// that side was replaced by a projection. Returns `None` if any of the sort
+/// expressions refers to a column that is not projected.
+fn update_join_sort_exprs(
+    projection_exprs: &[(Column, String)],
+    sort_exprs: &[PhysicalSortExpr],
+) -> Option<Vec<PhysicalSortExpr>> {
+    let exprs = sort_exprs
+        .iter()
+        .map(|sort_expr| &sort_expr.expr)
+        .collect_vec();
+    new_columns_for_join_on(&exprs, projection_exprs).map(|new_exprs| {
+        new_exprs
+            .into_iter()
+            .zip(sort_exprs)
+            .map(|(expr, sort_expr)| PhysicalSortExpr {
+                expr,
+                options: sort_expr.options,
+            })
+            .collect()
+    })
+}
+
 /// Tries to update the column indices of a [`JoinFilter`] as if the the input of
 /// the join was replaced by a projection.
 fn update_join_filter(
@@ -1204,6 +2187,7 @@ fn new_join_children(
 
 #[cfg(test)]
 mod tests {
+    use std::any::Any;
     use std::sync::Arc;
 
     use crate::datasource::file_format::file_compression_type::FileCompressionType;
@@ -1211,36 +2195,53 @@ mod tests {
     use crate::datasource::physical_plan::{CsvExec, FileScanConfig};
     use crate::physical_optimizer::output_requirements::OutputRequirementExec;
     use crate::physical_optimizer::projection_pushdown::{
-        join_table_borders, update_expr, ProjectionPushdown,
+        join_table_borders, try_unifying_projections, update_expr, ProjectionPushdown,
     };
     use crate::physical_optimizer::PhysicalOptimizerRule;
+    use crate::physical_plan::coalesce_batches::CoalesceBatchesExec;
     use crate::physical_plan::coalesce_partitions::CoalescePartitionsExec;
     use crate::physical_plan::filter::FilterExec;
     use crate::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
-    use crate::physical_plan::joins::StreamJoinPartitionMode;
+    use crate::physical_plan::joins::{
+        CrossJoinExec, HashJoinExec, NestedLoopJoinExec, PartitionMode,
+        SortMergeJoinExec, StreamJoinPartitionMode,
+    };
+    use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
     use crate::physical_plan::memory::MemoryExec;
+    use crate::physical_plan::placeholder_row::PlaceholderRowExec;
     use crate::physical_plan::projection::ProjectionExec;
     use crate::physical_plan::repartition::RepartitionExec;
     use crate::physical_plan::sorts::sort::SortExec;
     use crate::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
-    use crate::physical_plan::{get_plan_string, ExecutionPlan};
+    use crate::physical_plan::values::ValuesExec;
+    use crate::physical_plan::{
+        collect, get_plan_string, DisplayAs, DisplayFormatType, ExecutionMode,
+        ExecutionPlan, ExecutionPlanProperties, PlanProperties,
+    };
+    use crate::test::StatisticsExec;
 
-    use arrow_schema::{DataType, Field, Schema, SchemaRef, SortOptions};
+    use arrow::array::{ArrayRef, Int32Array};
+    use arrow::record_batch::RecordBatch;
+    use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef, SortOptions};
     use datafusion_common::config::ConfigOptions;
-    use datafusion_common::{JoinSide, JoinType, Result, ScalarValue, Statistics};
+    use datafusion_common::{
+        assert_batches_eq, assert_batches_sorted_eq, JoinSide, JoinType, Result,
+        ScalarValue, Statistics,
+    };
     use datafusion_execution::object_store::ObjectStoreUrl;
     use datafusion_execution::{SendableRecordBatchStream, TaskContext};
     use datafusion_expr::{ColumnarValue, Operator};
     use datafusion_physical_expr::expressions::{
-        BinaryExpr, CaseExpr, CastExpr, Column, Literal, NegativeExpr,
+        BinaryExpr, CaseExpr, CastExpr, Column, GetIndexedFieldExpr, IsNullExpr, Literal,
+        NegativeExpr, UnKnownColumn,
     };
     use datafusion_physical_expr::{
-        Distribution, Partitioning, PhysicalExpr, PhysicalSortExpr,
-        PhysicalSortRequirement, ScalarFunctionExpr,
+        Distribution, EquivalenceProperties, Partitioning, PhysicalExpr,
+        PhysicalSortExpr, PhysicalSortRequirement, ScalarFunctionExpr,
     };
     use datafusion_physical_plan::joins::SymmetricHashJoinExec;
     use datafusion_physical_plan::streaming::{PartitionStream, StreamingTableExec};
-    use datafusion_physical_plan::union::UnionExec;
+    use datafusion_physical_plan::union::{InterleaveExec, UnionExec};
 
     use itertools::Itertools;
 
@@ -1600,6 +2601,36 @@ mod tests {
         ))
     }
 
+    fn create_multi_partition_csv_exec() -> Arc<dyn ExecutionPlan> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Int32, true),
+            Field::new("d", DataType::Int32, true),
+            Field::new("e", DataType::Int32, true),
+        ]));
+        Arc::new(CsvExec::new(
+            FileScanConfig {
+                object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+                file_schema: schema.clone(),
+                file_groups: vec![
+                    vec![PartitionedFile::new("x".to_string(), 100)],
+                    vec![PartitionedFile::new("y".to_string(), 100)],
+                ],
+                statistics: Statistics::new_unknown(&schema),
+                projection: Some(vec![0, 1, 2, 3, 4]),
+                limit: None,
+                table_partition_cols: vec![],
+                output_ordering: vec![vec![]],
+            },
+            false,
+            0,
+            0,
+            None,
+            FileCompressionType::UNCOMPRESSED,
+        ))
+    }
+
     fn create_projecting_csv_exec() -> Arc<dyn ExecutionPlan> {
         let schema = Arc::new(Schema::new(vec![
             Field::new("a", DataType::Int32, true),
@@ -1626,6 +2657,23 @@ mod tests {
         ))
     }
 
+    fn create_i32_memory_exec(
+        columns: &[(&str, Vec<i32>)],
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let schema = Arc::new(Schema::new(
+            columns
+                .iter()
+                .map(|(name, _)| Field::new(*name, DataType::Int32, true))
+                .collect::<Vec<_>>(),
+        ));
+        let arrays = columns
+            .iter()
+            .map(|(_, values)| Arc::new(Int32Array::from(values.clone())) as ArrayRef)
+            .collect();
+        let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+        Ok(Arc::new(MemoryExec::try_new(&[vec![batch]], schema, None)?))
+    }
+
     fn create_projecting_memory_exec() -> Arc<dyn ExecutionPlan> {
         let schema = Arc::new(Schema::new(vec![
             Field::new("a", DataType::Int32, true),
@@ -1666,6 +2714,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reordering_projection_after_projecting_csv() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Int32, true),
+        ]));
+        let csv: Arc<dyn ExecutionPlan> = Arc::new(CsvExec::new(
+            FileScanConfig {
+                object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+                file_schema: schema.clone(),
+                file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
+                statistics: Statistics::new_unknown(&schema),
+                projection: Some(vec![0, 1]),
+                limit: None,
+                table_partition_cols: vec![],
+                output_ordering: vec![vec![]],
+            },
+            false,
+            0,
+            0,
+            None,
+            FileCompressionType::UNCOMPRESSED,
+        ));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            csv.clone(),
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize = ProjectionPushdown::new()
+            .optimize(projection.clone(), &ConfigOptions::new())?;
+
+        // The reordering is absorbed by the scan, and the schema is preserved:
+        let expected = [
+            "CsvExec: file_groups={1 group: [[x]]}, projection=[b, a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        assert_eq!(after_optimize.schema(), projection.schema());
+
+        Ok(())
+    }
+
     #[test]
     fn test_memory_after_projection() -> Result<()> {
         let memory = create_projecting_memory_exec();
@@ -1865,6 +2964,116 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_partially_unified_projections() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let child_projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("a", 0)),
+                        Operator::Plus,
+                        Arc::new(Column::new("b", 1)),
+                    )),
+                    "x".to_string(),
+                ),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+                (Arc::new(Column::new("e", 4)), "e".to_string()),
+            ],
+            csv.clone(),
+        )?);
+        let top_projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("x", 0)),
+                        Operator::Multiply,
+                        Arc::new(Column::new("x", 0)),
+                    )),
+                    "y".to_string(),
+                ),
+                (Arc::new(Column::new("c", 1)), "new_c".to_string()),
+            ],
+            child_projection.clone(),
+        )?);
+
+        let initial = get_plan_string(&top_projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[x@0 * x@0 as y, c@1 as new_c]",
+            "  ProjectionExec: expr=[a@0 + b@1 as x, c@2 as c, d@3 as d, e@4 as e]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(top_projection, &ConfigOptions::new())?;
+
+        // `x` is still computed once in the child projection, while the
+        // reference to `c` is unified and unused child expressions are dropped:
+        let expected = [
+            "ProjectionExec: expr=[x@0 * x@0 as y, c@1 as new_c]",
+            "  ProjectionExec: expr=[a@0 + b@1 as x, c@2 as c]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_partially_unified_projections_with_name_collision() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let child_projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("b", 1)),
+                        Operator::Plus,
+                        Arc::new(Column::new("c", 2)),
+                    )),
+                    "a".to_string(),
+                ),
+                (Arc::new(Column::new("a", 0)), "z".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+                (Arc::new(Column::new("e", 4)), "e".to_string()),
+            ],
+            csv.clone(),
+        )?);
+        let top_projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("a", 0)),
+                        Operator::Multiply,
+                        Arc::new(Column::new("a", 0)),
+                    )),
+                    "y".to_string(),
+                ),
+                (Arc::new(Column::new("z", 1)), "new_z".to_string()),
+            ],
+            child_projection.clone(),
+        )?);
+
+        let initial = get_plan_string(&top_projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 * a@0 as y, z@1 as new_z]",
+            "  ProjectionExec: expr=[b@1 + c@2 as a, a@0 as z, d@3 as d, e@4 as e]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(top_projection, &ConfigOptions::new())?;
+
+        // Unifying the reference to `z` would add a pass-through column `a`
+        // next to the cached expression aliased `a`, so the projections are
+        // left as they are:
+        assert_eq!(get_plan_string(&after_optimize), expected_initial);
+
+        Ok(())
+    }
+
     #[test]
     fn test_output_req_after_projection() -> Result<()> {
         let csv = create_simple_csv_exec();
@@ -1997,36 +3206,74 @@ mod tests {
     }
 
     #[test]
-    fn test_filter_after_projection() -> Result<()> {
+    fn test_coalesce_batches_over_filter_after_projection() -> Result<()> {
         let csv = create_simple_csv_exec();
         let predicate = Arc::new(BinaryExpr::new(
-            Arc::new(BinaryExpr::new(
-                Arc::new(Column::new("b", 1)),
-                Operator::Minus,
-                Arc::new(Column::new("a", 0)),
-            )),
+            Arc::new(Column::new("b", 1)),
             Operator::Gt,
+            Arc::new(Literal::new(ScalarValue::Int32(Some(0)))),
+        ));
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, csv)?);
+        let coalesce_batches: Arc<dyn ExecutionPlan> =
+            Arc::new(CoalesceBatchesExec::new(filter, 1024));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("a", 0)), "a".to_string())],
+            coalesce_batches,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+                "ProjectionExec: expr=[a@0 as a]",
+                "  CoalesceBatchesExec: target_batch_size=1024",
+                "    FilterExec: b@1 > 0",
+                "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The filter still needs `b`, so the scan reads `a` and `b`, and the
+        // projection on `a` stays on top of the filter:
+        let expected = [
+                "CoalesceBatchesExec: target_batch_size=1024",
+                "  ProjectionExec: expr=[a@0 as a]",
+                "    FilterExec: b@1 > 0",
+                "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_with_unknown_column_after_projection() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        // b > 0 AND c IS NULL, where `c` cannot be resolved to an index
+        let predicate = Arc::new(BinaryExpr::new(
             Arc::new(BinaryExpr::new(
-                Arc::new(Column::new("d", 3)),
-                Operator::Minus,
-                Arc::new(Column::new("a", 0)),
+                Arc::new(Column::new("b", 1)),
+                Operator::Gt,
+                Arc::new(Literal::new(ScalarValue::Int32(Some(0)))),
             )),
+            Operator::And,
+            Arc::new(IsNullExpr::new(Arc::new(UnKnownColumn::new("c")))),
         ));
         let filter: Arc<dyn ExecutionPlan> =
             Arc::new(FilterExec::try_new(predicate, csv)?);
         let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
             vec![
-                (Arc::new(Column::new("a", 0)), "a_new".to_string()),
                 (Arc::new(Column::new("b", 1)), "b".to_string()),
-                (Arc::new(Column::new("d", 3)), "d".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
             ],
-            filter.clone(),
+            filter,
         )?);
 
         let initial = get_plan_string(&projection);
         let expected_initial = [
-                "ProjectionExec: expr=[a@0 as a_new, b@1 as b, d@3 as d]",
-                "  FilterExec: b@1 - a@0 > d@3 - a@0",
+                "ProjectionExec: expr=[b@1 as b, a@0 as a]",
+                "  FilterExec: b@1 > 0 AND c IS NULL",
                 "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
         ];
         assert_eq!(initial, expected_initial);
@@ -2034,132 +3281,330 @@ mod tests {
         let after_optimize =
             ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
 
+        // The projection must stay above the filter, otherwise `c` is pruned:
+        assert_eq!(get_plan_string(&after_optimize), expected_initial);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_global_limit_over_ordered_merge_after_projection() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+        ]));
+        let batch = |a: Vec<i32>, b: Vec<i32>| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int32Array::from(a)), Arc::new(Int32Array::from(b))],
+            )
+        };
+        let sort_exprs = vec![PhysicalSortExpr {
+            expr: Arc::new(Column::new("b", 1)),
+            options: SortOptions::default(),
+        }];
+        // Both partitions are sorted on `b`:
+        let memory: Arc<dyn ExecutionPlan> = Arc::new(
+            MemoryExec::try_new(
+                &[
+                    vec![batch(vec![1, 2, 3], vec![1, 3, 5])?],
+                    vec![batch(vec![4, 5, 6], vec![2, 4, 6])?],
+                ],
+                schema.clone(),
+                None,
+            )?
+            .with_sort_information(vec![sort_exprs.clone()]),
+        );
+        let spm: Arc<dyn ExecutionPlan> =
+            Arc::new(SortPreservingMergeExec::new(sort_exprs, memory));
+        let global_limit: Arc<dyn ExecutionPlan> =
+            Arc::new(GlobalLimitExec::new(spm, 0, Some(3)));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("a", 0)), "a".to_string())],
+            global_limit,
+        )?);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The projection stops at the merge, since the merge needs `b` to
+        // order the rows the limit takes:
         let expected = [
-                "FilterExec: b@1 - a_new@0 > d@2 - a_new@0",
-                "  ProjectionExec: expr=[a@0 as a_new, b@1 as b, d@3 as d]",
-                "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "GlobalLimitExec: skip=0, fetch=3",
+            "  ProjectionExec: expr=[a@0 as a]",
+            "    SortPreservingMergeExec: [b@1 ASC]",
+            "      MemoryExec: partitions=2, partition_sizes=[1, 1], output_ordering=b@1 ASC",
         ];
         assert_eq!(get_plan_string(&after_optimize), expected);
 
+        let batches = collect(after_optimize, Arc::new(TaskContext::default())).await?;
+        let expected = [
+            "+---+", //
+            "| a |", //
+            "+---+", //
+            "| 1 |", //
+            "| 4 |", //
+            "| 2 |", //
+            "+---+",
+        ];
+        assert_batches_eq!(expected, &batches);
+
         Ok(())
     }
 
     #[test]
-    fn test_join_after_projection() -> Result<()> {
-        let left_csv = create_simple_csv_exec();
-        let right_csv = create_simple_csv_exec();
-
-        let join: Arc<dyn ExecutionPlan> = Arc::new(SymmetricHashJoinExec::try_new(
-            left_csv,
-            right_csv,
-            vec![(Arc::new(Column::new("b", 1)), Arc::new(Column::new("c", 2)))],
-            // b_left-(1+a_right)<=a_right+c_left
-            Some(JoinFilter::new(
-                Arc::new(BinaryExpr::new(
-                    Arc::new(BinaryExpr::new(
-                        Arc::new(Column::new("b_left_inter", 0)),
-                        Operator::Minus,
-                        Arc::new(BinaryExpr::new(
-                            Arc::new(Literal::new(ScalarValue::Int32(Some(1)))),
-                            Operator::Plus,
-                            Arc::new(Column::new("a_right_inter", 1)),
-                        )),
-                    )),
-                    Operator::LtEq,
-                    Arc::new(BinaryExpr::new(
-                        Arc::new(Column::new("a_right_inter", 1)),
-                        Operator::Plus,
-                        Arc::new(Column::new("c_left_inter", 2)),
-                    )),
-                )),
-                vec![
-                    ColumnIndex {
-                        index: 1,
-                        side: JoinSide::Left,
-                    },
-                    ColumnIndex {
-                        index: 0,
-                        side: JoinSide::Right,
-                    },
-                    ColumnIndex {
-                        index: 2,
-                        side: JoinSide::Left,
-                    },
-                ],
-                Schema::new(vec![
-                    Field::new("b_left_inter", DataType::Int32, true),
-                    Field::new("a_right_inter", DataType::Int32, true),
-                    Field::new("c_left_inter", DataType::Int32, true),
-                ]),
-            )),
-            &JoinType::Inner,
-            true,
-            None,
-            None,
-            StreamJoinPartitionMode::SinglePartition,
-        )?);
+    fn test_limit_lowering_after_projection() -> Result<()> {
+        let csv = create_multi_partition_csv_exec();
+        let local_limit: Arc<dyn ExecutionPlan> = Arc::new(LocalLimitExec::new(csv, 12));
+        let coalesce_partitions: Arc<dyn ExecutionPlan> =
+            Arc::new(CoalescePartitionsExec::new(local_limit));
+        let global_limit: Arc<dyn ExecutionPlan> =
+            Arc::new(GlobalLimitExec::new(coalesce_partitions, 2, Some(10)));
         let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
             vec![
-                (Arc::new(Column::new("c", 2)), "c_from_left".to_string()),
-                (Arc::new(Column::new("b", 1)), "b_from_left".to_string()),
-                (Arc::new(Column::new("a", 0)), "a_from_left".to_string()),
-                (Arc::new(Column::new("a", 5)), "a_from_right".to_string()),
-                (Arc::new(Column::new("c", 7)), "c_from_right".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
             ],
-            join,
+            global_limit,
         )?);
         let initial = get_plan_string(&projection);
         let expected_initial = [
-            "ProjectionExec: expr=[c@2 as c_from_left, b@1 as b_from_left, a@0 as a_from_left, a@5 as a_from_right, c@7 as c_from_right]", 
-            "  SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(b@1, c@2)], filter=b_left_inter@0 - 1 + a_right_inter@1 <= a_right_inter@1 + c_left_inter@2", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
-            ];
+            "ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "  GlobalLimitExec: skip=2, fetch=10",
+            "    CoalescePartitionsExec",
+            "      LocalLimitExec: fetch=12",
+            "        CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
         assert_eq!(initial, expected_initial);
 
         let after_optimize =
             ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
 
         let expected = [
-            "SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(b_from_left@1, c_from_right@1)], filter=b_left_inter@0 - 1 + a_right_inter@1 <= a_right_inter@1 + c_left_inter@2", 
-            "  ProjectionExec: expr=[c@2 as c_from_left, b@1 as b_from_left, a@0 as a_from_left]", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
-            "  ProjectionExec: expr=[a@0 as a_from_right, c@2 as c_from_right]", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
-            ];
-        assert_eq!(get_plan_string(&after_optimize), expected);
-
-        let expected_filter_col_ind = vec![
-            ColumnIndex {
-                index: 1,
-                side: JoinSide::Left,
-            },
-            ColumnIndex {
-                index: 0,
-                side: JoinSide::Right,
-            },
-            ColumnIndex {
-                index: 0,
-                side: JoinSide::Left,
-            },
+            "GlobalLimitExec: skip=2, fetch=10",
+            "  CoalescePartitionsExec",
+            "    LocalLimitExec: fetch=12",
+            "      CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[b, a], has_header=false",
         ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
 
-        assert_eq!(
-            expected_filter_col_ind,
-            after_optimize
-                .as_any()
-                .downcast_ref::<SymmetricHashJoinExec>()
-                .unwrap()
-                .filter()
-                .unwrap()
-                .column_indices()
-        );
+        let global_limit = after_optimize
+            .as_any()
+            .downcast_ref::<GlobalLimitExec>()
+            .unwrap();
+        assert_eq!(global_limit.skip(), 2);
+        assert_eq!(global_limit.fetch(), Some(10));
+        let local_limit = global_limit.input().children()[0].clone();
+        let local_limit = local_limit
+            .as_any()
+            .downcast_ref::<LocalLimitExec>()
+            .unwrap();
+        assert_eq!(local_limit.fetch(), 12);
 
         Ok(())
     }
 
     #[test]
-    fn test_join_after_required_projection() -> Result<()> {
+    fn test_global_limit_after_projection() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let sort: Arc<dyn ExecutionPlan> = Arc::new(SortExec::new(
+            vec![PhysicalSortExpr {
+                expr: Arc::new(Column::new("b", 1)),
+                options: SortOptions::default(),
+            }],
+            csv,
+        ));
+        let global_limit: Arc<dyn ExecutionPlan> =
+            Arc::new(GlobalLimitExec::new(sort, 0, Some(10)));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("a", 0)), "a".to_string())],
+            global_limit,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a]",
+            "  GlobalLimitExec: skip=0, fetch=10",
+            "    SortExec: expr=[b@1 ASC]",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The sort still needs `b`, so the scan reads `a` and `b`, and the
+        // projection on `a` stays on top of the sort:
+        let expected = [
+            "GlobalLimitExec: skip=0, fetch=10",
+            "  ProjectionExec: expr=[a@0 as a]",
+            "    SortExec: expr=[b@1 ASC]",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_after_projection() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let predicate = Arc::new(BinaryExpr::new(
+            Arc::new(BinaryExpr::new(
+                Arc::new(Column::new("b", 1)),
+                Operator::Minus,
+                Arc::new(Column::new("a", 0)),
+            )),
+            Operator::Gt,
+            Arc::new(BinaryExpr::new(
+                Arc::new(Column::new("d", 3)),
+                Operator::Minus,
+                Arc::new(Column::new("a", 0)),
+            )),
+        ));
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, csv)?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a_new".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+            ],
+            filter.clone(),
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+                "ProjectionExec: expr=[a@0 as a_new, b@1 as b, d@3 as d]",
+                "  FilterExec: b@1 - a@0 > d@3 - a@0",
+                "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+                "FilterExec: b@1 - a_new@0 > d@2 - a_new@0",
+                "  ProjectionExec: expr=[a@0 as a_new, b@1 as b, d@3 as d]",
+                "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_user_aliases_survive_pushdown() -> Result<()> {
+        let csv = create_multi_partition_csv_exec();
+        let predicate = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("a", 0)),
+            Operator::Gt,
+            Arc::new(Literal::new(ScalarValue::Int32(Some(0)))),
+        ));
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, csv)?);
+        let coalesce_partitions: Arc<dyn ExecutionPlan> =
+            Arc::new(CoalescePartitionsExec::new(filter));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "foo".to_string()),
+                (Arc::new(Column::new("b", 1)), "bar".to_string()),
+            ],
+            coalesce_partitions,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as foo, b@1 as bar]",
+            "  CoalescePartitionsExec",
+            "    FilterExec: a@0 > 0",
+            "      CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "CoalescePartitionsExec",
+            "  FilterExec: foo@0 > 0",
+            "    ProjectionExec: expr=[a@0 as foo, b@1 as bar]",
+            "      CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        let output_names = after_optimize
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(output_names, vec!["foo", "bar"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_removal_only() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let predicate = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("b", 1)),
+            Operator::Gt,
+            Arc::new(Literal::new(ScalarValue::Int32(Some(0)))),
+        ));
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, csv)?);
+        let identity: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+                (Arc::new(Column::new("e", 4)), "e".to_string()),
+            ],
+            filter,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            identity,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "  ProjectionExec: expr=[a@0 as a, b@1 as b, c@2 as c, d@3 as d, e@4 as e]",
+            "    FilterExec: b@1 > 0",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize = ProjectionPushdown::removal_only()
+            .optimize(projection.clone(), &ConfigOptions::new())?;
+
+        // The redundant projection is gone, but the remaining one stays above
+        // the filter and the scan is not narrowed:
+        let expected = [
+            "ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "  FilterExec: b@1 > 0",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // The default rule pushes the projection all the way into the scan:
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+        let expected = [
+            "FilterExec: b@0 > 0",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[b, a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_after_projection() -> Result<()> {
         let left_csv = create_simple_csv_exec();
         let right_csv = create_simple_csv_exec();
 
@@ -2214,195 +3659,2917 @@ mod tests {
         )?);
         let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
             vec![
-                (Arc::new(Column::new("a", 5)), "a".to_string()),
-                (Arc::new(Column::new("b", 6)), "b".to_string()),
-                (Arc::new(Column::new("c", 7)), "c".to_string()),
-                (Arc::new(Column::new("d", 8)), "d".to_string()),
-                (Arc::new(Column::new("e", 9)), "e".to_string()),
+                (Arc::new(Column::new("c", 2)), "c_from_left".to_string()),
+                (Arc::new(Column::new("b", 1)), "b_from_left".to_string()),
+                (Arc::new(Column::new("a", 0)), "a_from_left".to_string()),
+                (Arc::new(Column::new("a", 5)), "a_from_right".to_string()),
+                (Arc::new(Column::new("c", 7)), "c_from_right".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[c@2 as c_from_left, b@1 as b_from_left, a@0 as a_from_left, a@5 as a_from_right, c@7 as c_from_right]", 
+            "  SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(b@1, c@2)], filter=b_left_inter@0 - 1 + a_right_inter@1 <= a_right_inter@1 + c_left_inter@2", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(b_from_left@1, c_from_right@1)], filter=b_left_inter@0 - 1 + a_right_inter@1 <= a_right_inter@1 + c_left_inter@2", 
+            "  ProjectionExec: expr=[c@2 as c_from_left, b@1 as b_from_left, a@0 as a_from_left]", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "  ProjectionExec: expr=[a@0 as a_from_right, c@2 as c_from_right]", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        let expected_filter_col_ind = vec![
+            ColumnIndex {
+                index: 1,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Right,
+            },
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Left,
+            },
+        ];
+
+        assert_eq!(
+            expected_filter_col_ind,
+            after_optimize
+                .as_any()
+                .downcast_ref::<SymmetricHashJoinExec>()
+                .unwrap()
+                .filter()
+                .unwrap()
+                .column_indices()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sorted_join_after_projection() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Int32, true),
+        ]));
+        let sorted_memory_exec = || -> Result<Arc<dyn ExecutionPlan>> {
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(vec![1, 1, 1, 1, 1])),
+                    Arc::new(Int32Array::from(vec![10, 20, 30, 40, 50])),
+                    Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5])),
+                ],
+            )?;
+            let memory = MemoryExec::try_new(&[vec![batch]], schema.clone(), None)?
+                .with_sort_information(vec![vec![PhysicalSortExpr {
+                    expr: Arc::new(Column::new("c", 2)),
+                    options: SortOptions::default(),
+                }]]);
+            Ok(Arc::new(memory))
+        };
+        let left = sorted_memory_exec()?;
+        let right = sorted_memory_exec()?;
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(SymmetricHashJoinExec::try_new(
+            left.clone(),
+            right.clone(),
+            vec![(Arc::new(Column::new("a", 0)), Arc::new(Column::new("a", 0)))],
+            // c_left>c_right-2 AND c_left<c_right+2
+            Some(JoinFilter::new(
+                Arc::new(BinaryExpr::new(
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("c_left_inter", 0)),
+                        Operator::Gt,
+                        Arc::new(BinaryExpr::new(
+                            Arc::new(Column::new("c_right_inter", 1)),
+                            Operator::Minus,
+                            Arc::new(Literal::new(ScalarValue::Int32(Some(2)))),
+                        )),
+                    )),
+                    Operator::And,
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("c_left_inter", 0)),
+                        Operator::Lt,
+                        Arc::new(BinaryExpr::new(
+                            Arc::new(Column::new("c_right_inter", 1)),
+                            Operator::Plus,
+                            Arc::new(Literal::new(ScalarValue::Int32(Some(2)))),
+                        )),
+                    )),
+                )),
+                vec![
+                    ColumnIndex {
+                        index: 2,
+                        side: JoinSide::Left,
+                    },
+                    ColumnIndex {
+                        index: 2,
+                        side: JoinSide::Right,
+                    },
+                ],
+                Schema::new(vec![
+                    Field::new("c_left_inter", DataType::Int32, true),
+                    Field::new("c_right_inter", DataType::Int32, true),
+                ]),
+            )),
+            &JoinType::Inner,
+            true,
+            left.output_ordering().map(|p| p.to_vec()),
+            right.output_ordering().map(|p| p.to_vec()),
+            StreamJoinPartitionMode::SinglePartition,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c_from_left".to_string()),
+                (Arc::new(Column::new("a", 0)), "a_from_left".to_string()),
+                (Arc::new(Column::new("c", 5)), "c_from_right".to_string()),
+                (Arc::new(Column::new("a", 3)), "a_from_right".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[c@2 as c_from_left, a@0 as a_from_left, c@5 as c_from_right, a@3 as a_from_right]",
+            "  SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(a@0, a@0)], filter=c_left_inter@0 > c_right_inter@1 - 2 AND c_left_inter@0 < c_right_inter@1 + 2",
+            "    MemoryExec: partitions=1, partition_sizes=[1], output_ordering=c@2 ASC",
+            "    MemoryExec: partitions=1, partition_sizes=[1], output_ordering=c@2 ASC",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(a_from_left@1, a_from_right@1)], filter=c_left_inter@0 > c_right_inter@1 - 2 AND c_left_inter@0 < c_right_inter@1 + 2",
+            "  ProjectionExec: expr=[c@2 as c_from_left, a@0 as a_from_left]",
+            "    MemoryExec: partitions=1, partition_sizes=[1], output_ordering=c@2 ASC",
+            "  ProjectionExec: expr=[c@2 as c_from_right, a@0 as a_from_right]",
+            "    MemoryExec: partitions=1, partition_sizes=[1], output_ordering=c@2 ASC",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        let sym_join = after_optimize
+            .as_any()
+            .downcast_ref::<SymmetricHashJoinExec>()
+            .unwrap();
+        let expected_sort_exprs = vec![PhysicalSortExpr {
+            expr: Arc::new(Column::new("c_from_left", 0)),
+            options: SortOptions::default(),
+        }];
+        assert_eq!(
+            sym_join.left_sort_exprs(),
+            Some(expected_sort_exprs.as_slice())
+        );
+        let expected_sort_exprs = vec![PhysicalSortExpr {
+            expr: Arc::new(Column::new("c_from_right", 0)),
+            options: SortOptions::default(),
+        }];
+        assert_eq!(
+            sym_join.right_sort_exprs(),
+            Some(expected_sort_exprs.as_slice())
+        );
+
+        let batches = collect(after_optimize, Arc::new(TaskContext::default())).await?;
+        let expected = [
+            "+-------------+-------------+--------------+--------------+",
+            "| c_from_left | a_from_left | c_from_right | a_from_right |",
+            "+-------------+-------------+--------------+--------------+",
+            "| 1           | 1           | 1            | 1            |",
+            "| 1           | 1           | 2            | 1            |",
+            "| 2           | 1           | 1            | 1            |",
+            "| 2           | 1           | 2            | 1            |",
+            "| 2           | 1           | 3            | 1            |",
+            "| 3           | 1           | 2            | 1            |",
+            "| 3           | 1           | 3            | 1            |",
+            "| 3           | 1           | 4            | 1            |",
+            "| 4           | 1           | 3            | 1            |",
+            "| 4           | 1           | 4            | 1            |",
+            "| 4           | 1           | 5            | 1            |",
+            "| 5           | 1           | 4            | 1            |",
+            "| 5           | 1           | 5            | 1            |",
+            "+-------------+-------------+--------------+--------------+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sorted_join_both_sides_after_projection() -> Result<()> {
+        let sorted_memory_exec = |columns: [(&str, Vec<i32>); 3],
+                                  sort_column: (&str, usize)|
+         -> Result<Arc<dyn ExecutionPlan>> {
+            let schema = Arc::new(Schema::new(
+                columns
+                    .iter()
+                    .map(|(name, _)| Field::new(*name, DataType::Int32, true))
+                    .collect::<Vec<_>>(),
+            ));
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                columns
+                    .into_iter()
+                    .map(|(_, values)| Arc::new(Int32Array::from(values)) as ArrayRef)
+                    .collect(),
+            )?;
+            let memory = MemoryExec::try_new(&[vec![batch]], schema, None)?
+                .with_sort_information(vec![vec![PhysicalSortExpr {
+                    expr: Arc::new(Column::new(sort_column.0, sort_column.1)),
+                    options: SortOptions::default(),
+                }]]);
+            Ok(Arc::new(memory))
+        };
+        // The left side is sorted on its second column, the right side on
+        // its third one:
+        let left = sorted_memory_exec(
+            [
+                ("a", vec![1, 1, 1, 1, 1]),
+                ("b", vec![1, 2, 3, 4, 5]),
+                ("c", vec![0, 0, 0, 0, 0]),
+            ],
+            ("b", 1),
+        )?;
+        let right = sorted_memory_exec(
+            [
+                ("a", vec![1, 1, 1, 1, 1]),
+                ("b", vec![0, 0, 0, 0, 0]),
+                ("c", vec![1, 2, 3, 4, 5]),
+            ],
+            ("c", 2),
+        )?;
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(SymmetricHashJoinExec::try_new(
+            left.clone(),
+            right.clone(),
+            vec![(Arc::new(Column::new("a", 0)), Arc::new(Column::new("a", 0)))],
+            // b_left>c_right-2 AND b_left<c_right+2
+            Some(JoinFilter::new(
+                Arc::new(BinaryExpr::new(
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("b_left_inter", 0)),
+                        Operator::Gt,
+                        Arc::new(BinaryExpr::new(
+                            Arc::new(Column::new("c_right_inter", 1)),
+                            Operator::Minus,
+                            Arc::new(Literal::new(ScalarValue::Int32(Some(2)))),
+                        )),
+                    )),
+                    Operator::And,
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("b_left_inter", 0)),
+                        Operator::Lt,
+                        Arc::new(BinaryExpr::new(
+                            Arc::new(Column::new("c_right_inter", 1)),
+                            Operator::Plus,
+                            Arc::new(Literal::new(ScalarValue::Int32(Some(2)))),
+                        )),
+                    )),
+                )),
+                vec![
+                    ColumnIndex {
+                        index: 1,
+                        side: JoinSide::Left,
+                    },
+                    ColumnIndex {
+                        index: 2,
+                        side: JoinSide::Right,
+                    },
+                ],
+                Schema::new(vec![
+                    Field::new("b_left_inter", DataType::Int32, true),
+                    Field::new("c_right_inter", DataType::Int32, true),
+                ]),
+            )),
+            &JoinType::Inner,
+            true,
+            left.output_ordering().map(|p| p.to_vec()),
+            right.output_ordering().map(|p| p.to_vec()),
+            StreamJoinPartitionMode::SinglePartition,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a_from_left".to_string()),
+                (Arc::new(Column::new("b", 1)), "b_from_left".to_string()),
+                (Arc::new(Column::new("c", 5)), "c_from_right".to_string()),
+                (Arc::new(Column::new("a", 3)), "a_from_right".to_string()),
+            ],
+            join,
+        )?);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(a_from_left@0, a_from_right@1)], filter=b_left_inter@0 > c_right_inter@1 - 2 AND b_left_inter@0 < c_right_inter@1 + 2",
+            "  ProjectionExec: expr=[a@0 as a_from_left, b@1 as b_from_left]",
+            "    MemoryExec: partitions=1, partition_sizes=[1], output_ordering=b@1 ASC",
+            "  ProjectionExec: expr=[c@2 as c_from_right, a@0 as a_from_right]",
+            "    MemoryExec: partitions=1, partition_sizes=[1], output_ordering=c@2 ASC",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // Each side's sort expressions follow that side's own projection:
+        let sym_join = after_optimize
+            .as_any()
+            .downcast_ref::<SymmetricHashJoinExec>()
+            .unwrap();
+        let expected_sort_exprs = vec![PhysicalSortExpr {
+            expr: Arc::new(Column::new("b_from_left", 1)),
+            options: SortOptions::default(),
+        }];
+        assert_eq!(
+            sym_join.left_sort_exprs(),
+            Some(expected_sort_exprs.as_slice())
+        );
+        let expected_sort_exprs = vec![PhysicalSortExpr {
+            expr: Arc::new(Column::new("c_from_right", 0)),
+            options: SortOptions::default(),
+        }];
+        assert_eq!(
+            sym_join.right_sort_exprs(),
+            Some(expected_sort_exprs.as_slice())
+        );
+
+        let batches = collect(after_optimize, Arc::new(TaskContext::default())).await?;
+        let expected = [
+            "+-------------+-------------+--------------+--------------+",
+            "| a_from_left | b_from_left | c_from_right | a_from_right |",
+            "+-------------+-------------+--------------+--------------+",
+            "| 1           | 1           | 1            | 1            |",
+            "| 1           | 1           | 2            | 1            |",
+            "| 1           | 2           | 1            | 1            |",
+            "| 1           | 2           | 2            | 1            |",
+            "| 1           | 2           | 3            | 1            |",
+            "| 1           | 3           | 2            | 1            |",
+            "| 1           | 3           | 3            | 1            |",
+            "| 1           | 3           | 4            | 1            |",
+            "| 1           | 4           | 3            | 1            |",
+            "| 1           | 4           | 4            | 1            |",
+            "| 1           | 4           | 5            | 1            |",
+            "| 1           | 5           | 4            | 1            |",
+            "| 1           | 5           | 5            | 1            |",
+            "+-------------+-------------+--------------+--------------+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_after_required_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(SymmetricHashJoinExec::try_new(
+            left_csv,
+            right_csv,
+            vec![(Arc::new(Column::new("b", 1)), Arc::new(Column::new("c", 2)))],
+            // b_left-(1+a_right)<=a_right+c_left
+            Some(JoinFilter::new(
+                Arc::new(BinaryExpr::new(
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("b_left_inter", 0)),
+                        Operator::Minus,
+                        Arc::new(BinaryExpr::new(
+                            Arc::new(Literal::new(ScalarValue::Int32(Some(1)))),
+                            Operator::Plus,
+                            Arc::new(Column::new("a_right_inter", 1)),
+                        )),
+                    )),
+                    Operator::LtEq,
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("a_right_inter", 1)),
+                        Operator::Plus,
+                        Arc::new(Column::new("c_left_inter", 2)),
+                    )),
+                )),
+                vec![
+                    ColumnIndex {
+                        index: 1,
+                        side: JoinSide::Left,
+                    },
+                    ColumnIndex {
+                        index: 0,
+                        side: JoinSide::Right,
+                    },
+                    ColumnIndex {
+                        index: 2,
+                        side: JoinSide::Left,
+                    },
+                ],
+                Schema::new(vec![
+                    Field::new("b_left_inter", DataType::Int32, true),
+                    Field::new("a_right_inter", DataType::Int32, true),
+                    Field::new("c_left_inter", DataType::Int32, true),
+                ]),
+            )),
+            &JoinType::Inner,
+            true,
+            None,
+            None,
+            StreamJoinPartitionMode::SinglePartition,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 5)), "a".to_string()),
+                (Arc::new(Column::new("b", 6)), "b".to_string()),
+                (Arc::new(Column::new("c", 7)), "c".to_string()),
+                (Arc::new(Column::new("d", 8)), "d".to_string()),
+                (Arc::new(Column::new("e", 9)), "e".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+                (Arc::new(Column::new("e", 4)), "e".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@5 as a, b@6 as b, c@7 as c, d@8 as d, e@9 as e, a@0 as a, b@1 as b, c@2 as c, d@3 as d, e@4 as e]", 
+            "  SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(b@1, c@2)], filter=b_left_inter@0 - 1 + a_right_inter@1 <= a_right_inter@1 + c_left_inter@2", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "ProjectionExec: expr=[a@5 as a, b@6 as b, c@7 as c, d@8 as d, e@9 as e, a@0 as a, b@1 as b, c@2 as c, d@3 as d, e@4 as e]", 
+            "  SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(b@1, c@2)], filter=b_left_inter@0 - 1 + a_right_inter@1 <= a_right_inter@1 + c_left_inter@2", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_nested_loop_join_without_filter_after_projection() -> Result<()> {
+        let join: Arc<dyn ExecutionPlan> = Arc::new(NestedLoopJoinExec::try_new(
+            create_simple_csv_exec(),
+            create_simple_csv_exec(),
+            None,
+            &JoinType::LeftSemi,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, c@2 as c]",
+            "  NestedLoopJoinExec: join_type=LeftSemi",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // Without a join filter, the right side only decides whether there is
+        // a match, so it is narrowed to a single column:
+        let expected = [
+            "NestedLoopJoinExec: join_type=LeftSemi",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, c], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_anti_nested_loop_join_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(NestedLoopJoinExec::try_new(
+            left_csv,
+            right_csv,
+            // a_left > b_right
+            Some(JoinFilter::new(
+                Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new("a_left_inter", 0)),
+                    Operator::Gt,
+                    Arc::new(Column::new("b_right_inter", 1)),
+                )),
+                vec![
+                    ColumnIndex {
+                        index: 0,
+                        side: JoinSide::Left,
+                    },
+                    ColumnIndex {
+                        index: 1,
+                        side: JoinSide::Right,
+                    },
+                ],
+                Schema::new(vec![
+                    Field::new("a_left_inter", DataType::Int32, true),
+                    Field::new("b_right_inter", DataType::Int32, true),
+                ]),
+            )),
+            &JoinType::LeftAnti,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, c@2 as c]",
+            "  NestedLoopJoinExec: join_type=LeftAnti, filter=a_left_inter@0 > b_right_inter@1",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The right side only keeps the column referenced by the join filter:
+        let expected = [
+            "NestedLoopJoinExec: join_type=LeftAnti, filter=a_left_inter@0 > b_right_inter@1",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, c], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[b], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        let expected_filter_col_ind = vec![
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Right,
+            },
+        ];
+        assert_eq!(
+            expected_filter_col_ind,
+            after_optimize
+                .as_any()
+                .downcast_ref::<NestedLoopJoinExec>()
+                .unwrap()
+                .filter()
+                .unwrap()
+                .column_indices()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_key_sort_merge_join_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let sort_options = vec![
+            SortOptions::default(),
+            SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        ];
+        let join: Arc<dyn ExecutionPlan> = Arc::new(SortMergeJoinExec::try_new(
+            left_csv,
+            right_csv,
+            vec![
+                (Arc::new(Column::new("b", 1)), Arc::new(Column::new("c", 2))),
+                (Arc::new(Column::new("d", 3)), Arc::new(Column::new("e", 4))),
+            ],
+            None,
+            JoinType::Inner,
+            sort_options.clone(),
+            false,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+                (Arc::new(Column::new("c", 7)), "c".to_string()),
+                (Arc::new(Column::new("e", 9)), "e".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, d@3 as d, c@7 as c, e@9 as e]",
+            "  SortMergeJoin: join_type=Inner, on=[(b@1, c@2), (d@3, e@4)]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SortMergeJoin: join_type=Inner, on=[(b@0, c@0), (d@1, e@1)]",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[b, d], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[c, e], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // Sort options must stay aligned with the (remapped) join keys:
+        let sm_join = after_optimize
+            .as_any()
+            .downcast_ref::<SortMergeJoinExec>()
+            .unwrap();
+        assert_eq!(sm_join.on().len(), sm_join.sort_options.len());
+        assert_eq!(sm_join.sort_options, sort_options);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_merge_join_with_filter_after_projection() -> Result<()> {
+        let sort_merge_join = || -> Result<Arc<dyn ExecutionPlan>> {
+            Ok(Arc::new(SortMergeJoinExec::try_new(
+                create_simple_csv_exec(),
+                create_simple_csv_exec(),
+                vec![(Arc::new(Column::new("b", 1)), Arc::new(Column::new("d", 3)))],
+                // a_left + c_left > e_right
+                Some(JoinFilter::new(
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(BinaryExpr::new(
+                            Arc::new(Column::new("a_left_inter", 0)),
+                            Operator::Plus,
+                            Arc::new(Column::new("c_left_inter", 1)),
+                        )),
+                        Operator::Gt,
+                        Arc::new(Column::new("e_right_inter", 2)),
+                    )),
+                    vec![
+                        ColumnIndex {
+                            index: 0,
+                            side: JoinSide::Left,
+                        },
+                        ColumnIndex {
+                            index: 2,
+                            side: JoinSide::Left,
+                        },
+                        ColumnIndex {
+                            index: 4,
+                            side: JoinSide::Right,
+                        },
+                    ],
+                    Schema::new(vec![
+                        Field::new("a_left_inter", DataType::Int32, true),
+                        Field::new("c_left_inter", DataType::Int32, true),
+                        Field::new("e_right_inter", DataType::Int32, true),
+                    ]),
+                )),
+                JoinType::Inner,
+                vec![SortOptions::default()],
+                false,
+            )?))
+        };
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("d", 8)), "d".to_string()),
+                (Arc::new(Column::new("e", 9)), "e".to_string()),
+            ],
+            sort_merge_join()?,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, b@1 as b, c@2 as c, d@8 as d, e@9 as e]",
+            "  SortMergeJoin: join_type=Inner, on=[(b@1, d@3)], filter=a_left_inter@0 + c_left_inter@1 > e_right_inter@2",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SortMergeJoin: join_type=Inner, on=[(b@1, d@0)], filter=a_left_inter@0 + c_left_inter@1 > e_right_inter@2",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[d, e], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // All three filter columns are remapped into the narrowed children:
+        let expected_filter_col_ind = vec![
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 2,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 1,
+                side: JoinSide::Right,
+            },
+        ];
+        assert_eq!(
+            expected_filter_col_ind,
+            after_optimize
+                .as_any()
+                .downcast_ref::<SortMergeJoinExec>()
+                .unwrap()
+                .filter
+                .as_ref()
+                .unwrap()
+                .column_indices()
+        );
+
+        // If one of the filter columns is not projected, the projection must
+        // stay above the join:
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("d", 8)), "d".to_string()),
+                (Arc::new(Column::new("e", 9)), "e".to_string()),
+            ],
+            sort_merge_join()?,
+        )?);
+        let initial = get_plan_string(&projection);
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+        assert_eq!(get_plan_string(&after_optimize), initial);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_join_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> =
+            Arc::new(CrossJoinExec::new(left_csv, right_csv));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("b", 6)), "b".to_string()),
+                (Arc::new(Column::new("d", 8)), "d".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, c@2 as c, b@6 as b, d@8 as d]",
+            "  CrossJoinExec",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // Both sides are narrowed within the scans, no projection is left over:
+        let expected = [
+            "CrossJoinExec",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, c], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[b, d], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_join_budget_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(
+            CrossJoinExec::new(left_csv, right_csv).with_max_output_batch_bytes(4096),
+        );
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 6)), "b".to_string()),
+            ],
+            join,
+        )?);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "CrossJoinExec",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[b], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // The output batch budget survives rebuilding the join:
+        let new_join = after_optimize
+            .as_any()
+            .downcast_ref::<CrossJoinExec>()
+            .unwrap();
+        assert_eq!(new_join.max_output_batch_bytes(), Some(4096));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_over_cross_join_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> =
+            Arc::new(CrossJoinExec::new(left_csv, right_csv));
+        let sort: Arc<dyn ExecutionPlan> = Arc::new(SortExec::new(
+            vec![PhysicalSortExpr {
+                expr: Arc::new(Column::new("a", 0)),
+                options: SortOptions::default(),
+            }],
+            join,
+        ));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("a", 0)), "a_from_left".to_string())],
+            sort,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a_from_left]",
+            "  SortExec: expr=[a@0 ASC]",
+            "    CrossJoinExec",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The sort key is remapped, but the projection stops above the cross
+        // join: none of the right columns are used, but the right side still
+        // determines the number of output rows. It is narrowed to a single
+        // column instead, and the left side only reads the used column:
+        let expected = [
+            "SortExec: expr=[a_from_left@0 ASC]",
+            "  ProjectionExec: expr=[a@0 as a_from_left]",
+            "    CrossJoinExec",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a], has_header=false",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_join_right_side_only_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> =
+            Arc::new(CrossJoinExec::new(left_csv, right_csv));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("d", 8)), "d".to_string()),
+                (Arc::new(Column::new("b", 6)), "b_from_right".to_string()),
+                (Arc::new(Column::new("d", 8)), "d2".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[d@8 as d, b@6 as b_from_right, d@8 as d2]",
+            "  CrossJoinExec",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The left side is narrowed to one column, and the indices of the
+        // right columns are shifted accordingly:
+        let expected = [
+            "ProjectionExec: expr=[d@2 as d, b@1 as b_from_right, d@2 as d2]",
+            "  CrossJoinExec",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[b, d], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_left_semi_hash_join_dropping_keys_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(HashJoinExec::try_new(
+            left_csv,
+            right_csv,
+            vec![(Arc::new(Column::new("a", 0)), Arc::new(Column::new("a", 0)))],
+            None,
+            &JoinType::LeftSemi,
+            PartitionMode::CollectLeft,
+            false,
+        )?);
+        // SELECT b FROM l LEFT SEMI JOIN r ON l.a = r.a
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("b", 1)), "b".to_string())],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b]",
+            "  HashJoinExec: mode=CollectLeft, join_type=LeftSemi, on=[(a@0, a@0)]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The left join key is not projected, so the left side keeps it along
+        // with `b`, and the projection on `b` stays on top of the join:
+        let expected = [
+            "ProjectionExec: expr=[b@1 as b]",
+            "  HashJoinExec: mode=CollectLeft, join_type=LeftSemi, on=[(a@0, a@0)]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_right_semi_hash_join_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(HashJoinExec::try_new(
+            left_csv,
+            right_csv,
+            vec![(Arc::new(Column::new("b", 1)), Arc::new(Column::new("c", 2)))],
+            // a_left > d_right
+            Some(JoinFilter::new(
+                Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new("a_left_inter", 0)),
+                    Operator::Gt,
+                    Arc::new(Column::new("d_right_inter", 1)),
+                )),
+                vec![
+                    ColumnIndex {
+                        index: 0,
+                        side: JoinSide::Left,
+                    },
+                    ColumnIndex {
+                        index: 3,
+                        side: JoinSide::Right,
+                    },
+                ],
+                Schema::new(vec![
+                    Field::new("a_left_inter", DataType::Int32, true),
+                    Field::new("d_right_inter", DataType::Int32, true),
+                ]),
+            )),
+            &JoinType::RightSemi,
+            PartitionMode::CollectLeft,
+            false,
+        )?);
+        // The join only outputs the right columns:
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[c@2 as c, d@3 as d]",
+            "  HashJoinExec: mode=CollectLeft, join_type=RightSemi, on=[(b@1, c@2)], filter=a_left_inter@0 > d_right_inter@1",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The right side keeps the projected columns, and the left side only
+        // keeps the columns referenced by the join key and the join filter:
+        let expected = [
+            "HashJoinExec: mode=CollectLeft, join_type=RightSemi, on=[(b@1, c@0)], filter=a_left_inter@0 > d_right_inter@1",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[c, d], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        let expected_filter_col_ind = vec![
+            ColumnIndex {
+                index: 0,
+                side: JoinSide::Left,
+            },
+            ColumnIndex {
+                index: 1,
+                side: JoinSide::Right,
+            },
+        ];
+        assert_eq!(
+            expected_filter_col_ind,
+            after_optimize
+                .as_any()
+                .downcast_ref::<HashJoinExec>()
+                .unwrap()
+                .filter()
+                .unwrap()
+                .column_indices()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_join_below_hash_join_after_projection() -> Result<()> {
+        let cross_join: Arc<dyn ExecutionPlan> = Arc::new(CrossJoinExec::new(
+            create_simple_csv_exec(),
+            create_simple_csv_exec(),
+        ));
+        let join: Arc<dyn ExecutionPlan> = Arc::new(HashJoinExec::try_new(
+            cross_join,
+            create_simple_csv_exec(),
+            vec![(Arc::new(Column::new("a", 0)), Arc::new(Column::new("c", 2)))],
+            None,
+            &JoinType::Inner,
+            PartitionMode::CollectLeft,
+            false,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 6)), "b".to_string()),
+                (Arc::new(Column::new("c", 12)), "c".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, b@6 as b, c@12 as c]",
+            "  HashJoinExec: mode=CollectLeft, join_type=Inner, on=[(a@0, c@2)]",
+            "    CrossJoinExec",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The projection pushed into the left side of the hash join continues
+        // through the cross join, so every scan only reads what it provides:
+        let expected = [
+            "HashJoinExec: mode=CollectLeft, join_type=Inner, on=[(a@0, c@0)]",
+            "  CrossJoinExec",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[b], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[c], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_self_join_after_projection() -> Result<()> {
+        // The same scan is used on both sides of the join:
+        let csv = create_simple_csv_exec();
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(HashJoinExec::try_new(
+            csv.clone(),
+            csv,
+            vec![(Arc::new(Column::new("a", 0)), Arc::new(Column::new("c", 2)))],
+            None,
+            &JoinType::Inner,
+            PartitionMode::CollectLeft,
+            false,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("c", 7)), "c".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, b@1 as b, c@7 as c]",
+            "  HashJoinExec: mode=CollectLeft, join_type=Inner, on=[(a@0, c@2)]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // Each side is narrowed independently to the columns it provides:
+        let expected = [
+            "HashJoinExec: mode=CollectLeft, join_type=Inner, on=[(a@0, c@0)]",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[c], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_join_with_expr_key_after_projection() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_csv = create_simple_csv_exec();
+
+        // a_left + 1 = c_right
+        let join: Arc<dyn ExecutionPlan> = Arc::new(HashJoinExec::try_new(
+            left_csv,
+            right_csv,
+            vec![(
+                Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new("a", 0)),
+                    Operator::Plus,
+                    Arc::new(Literal::new(ScalarValue::Int32(Some(1)))),
+                )),
+                Arc::new(Column::new("c", 2)),
+            )],
+            None,
+            &JoinType::Inner,
+            PartitionMode::Partitioned,
+            true,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("c", 7)), "c".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, a@0 as a, c@7 as c]",
+            "  HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@0 + 1, c@2)]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The whole key expression is remapped, not only bare columns:
+        let expected = [
+            "HashJoinExec: mode=Partitioned, join_type=Inner, on=[(a@1 + 1, c@0)]",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[b, a], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[c], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        let hash_join = after_optimize
+            .as_any()
+            .downcast_ref::<HashJoinExec>()
+            .unwrap();
+        assert_eq!(*hash_join.partition_mode(), PartitionMode::Partitioned);
+        assert!(hash_join.null_equals_null());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_repartition_after_projection() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let repartition: Arc<dyn ExecutionPlan> = Arc::new(RepartitionExec::try_new(
+            csv,
+            Partitioning::Hash(
+                vec![
+                    Arc::new(Column::new("a", 0)),
+                    Arc::new(Column::new("b", 1)),
+                    Arc::new(Column::new("d", 3)),
+                ],
+                6,
+            ),
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b_new".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("d", 3)), "d_new".to_string()),
+            ],
+            repartition,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+                "ProjectionExec: expr=[b@1 as b_new, a@0 as a, d@3 as d_new]",
+                "  RepartitionExec: partitioning=Hash([a@0, b@1, d@3], 6), input_partitions=1",
+                "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+                "RepartitionExec: partitioning=Hash([a@1, b_new@0, d_new@2], 6), input_partitions=1", 
+                "  ProjectionExec: expr=[b@1 as b_new, a@0 as a, d@3 as d_new]", 
+                "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        assert_eq!(
+            after_optimize
+                .as_any()
+                .downcast_ref::<RepartitionExec>()
+                .unwrap()
+                .partitioning()
+                .clone(),
+            Partitioning::Hash(
+                vec![
+                    Arc::new(Column::new("a", 1)),
+                    Arc::new(Column::new("b_new", 0)),
+                    Arc::new(Column::new("d_new", 2)),
+                ],
+                6,
+            ),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_repartition_on_only_projected_column() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let repartition: Arc<dyn ExecutionPlan> = Arc::new(RepartitionExec::try_new(
+            csv,
+            Partitioning::Hash(vec![Arc::new(Column::new("c", 2))], 4),
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("c", 2)), "c".to_string())],
+            repartition,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+                "ProjectionExec: expr=[c@2 as c]",
+                "  RepartitionExec: partitioning=Hash([c@2], 4), input_partitions=1",
+                "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The hash key is the only column left, and the projection is
+        // absorbed by the scan:
+        let expected = [
+            "RepartitionExec: partitioning=Hash([c@0], 4), input_partitions=1",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[c], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        assert_eq!(
+            after_optimize
+                .as_any()
+                .downcast_ref::<RepartitionExec>()
+                .unwrap()
+                .partitioning()
+                .clone(),
+            Partitioning::Hash(vec![Arc::new(Column::new("c", 0))], 4),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_after_projection() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let sort_req: Arc<dyn ExecutionPlan> = Arc::new(SortExec::new(
+            vec![
+                PhysicalSortExpr {
+                    expr: Arc::new(Column::new("b", 1)),
+                    options: SortOptions::default(),
+                },
+                PhysicalSortExpr {
+                    expr: Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("c", 2)),
+                        Operator::Plus,
+                        Arc::new(Column::new("a", 0)),
+                    )),
+                    options: SortOptions::default(),
+                },
+            ],
+            csv.clone(),
+        ));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("a", 0)), "new_a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+            ],
+            sort_req.clone(),
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]",
+            "  SortExec: expr=[b@1 ASC,c@2 + a@0 ASC]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SortExec: expr=[b@2 ASC,c@0 + new_a@1 ASC]",
+            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_preserving_after_projection() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let sort_req: Arc<dyn ExecutionPlan> = Arc::new(SortPreservingMergeExec::new(
+            vec![
+                PhysicalSortExpr {
+                    expr: Arc::new(Column::new("b", 1)),
+                    options: SortOptions::default(),
+                },
+                PhysicalSortExpr {
+                    expr: Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("c", 2)),
+                        Operator::Plus,
+                        Arc::new(Column::new("a", 0)),
+                    )),
+                    options: SortOptions::default(),
+                },
+            ],
+            csv.clone(),
+        ));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("a", 0)), "new_a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+            ],
+            sort_req.clone(),
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]",
+            "  SortPreservingMergeExec: [b@1 ASC,c@2 + a@0 ASC]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SortPreservingMergeExec: [b@2 ASC,c@0 + new_a@1 ASC]",
+            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_after_projection() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let union: Arc<dyn ExecutionPlan> =
+            Arc::new(UnionExec::new(vec![csv.clone(), csv.clone(), csv]));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("a", 0)), "new_a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+            ],
+            union.clone(),
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]", 
+            "  UnionExec", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "UnionExec", 
+            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]", 
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_over_union_after_projection() -> Result<()> {
+        let union: Arc<dyn ExecutionPlan> = Arc::new(UnionExec::new(vec![
+            create_simple_csv_exec(),
+            create_simple_csv_exec(),
+        ]));
+        let predicate = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("b", 1)),
+            Operator::Gt,
+            Arc::new(Literal::new(ScalarValue::Int32(Some(0)))),
+        ));
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, union)?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            filter,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "  FilterExec: b@1 > 0",
+            "    UnionExec",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The projection passes the filter and is then copied to every union child:
+        let expected = [
+            "FilterExec: b@0 > 0",
+            "  UnionExec",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[b, a], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[b, a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_projection_over_union_of_projections() -> Result<()> {
+        let child_projection = |exprs: [(&str, usize, &str); 3]| {
+            ProjectionExec::try_new(
+                exprs
+                    .into_iter()
+                    .map(|(name, index, alias)| {
+                        (
+                            Arc::new(Column::new(name, index)) as Arc<dyn PhysicalExpr>,
+                            alias.to_string(),
+                        )
+                    })
+                    .collect(),
+                create_simple_csv_exec(),
+            )
+            .map(|e| Arc::new(e) as Arc<dyn ExecutionPlan>)
+        };
+        let union: Arc<dyn ExecutionPlan> = Arc::new(UnionExec::new(vec![
+            child_projection([("a", 0, "a"), ("c", 2, "b"), ("e", 4, "e")])?,
+            child_projection([("b", 1, "a"), ("d", 3, "b"), ("a", 0, "e")])?,
+        ]));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("b", 1)), "b".to_string())],
+            union,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b]",
+            "  UnionExec",
+            "    ProjectionExec: expr=[a@0 as a, c@2 as b, e@4 as e]",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    ProjectionExec: expr=[b@1 as a, d@3 as b, a@0 as e]",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The projection is not unified with the projections below the union,
+        // it is copied into each child and unified there with that child only:
+        let expected = [
+            "UnionExec",
+            "  ProjectionExec: expr=[c@2 as b]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "  ProjectionExec: expr=[d@3 as b]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interleave_after_projection() -> Result<()> {
+        let partitioned_csv = || -> Result<Arc<dyn ExecutionPlan>> {
+            Ok(Arc::new(RepartitionExec::try_new(
+                create_simple_csv_exec(),
+                Partitioning::Hash(vec![Arc::new(Column::new("a", 0))], 4),
+            )?))
+        };
+        let interleave: Arc<dyn ExecutionPlan> =
+            Arc::new(InterleaveExec::try_new(vec![
+                partitioned_csv()?,
+                partitioned_csv()?,
+            ])?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            interleave,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[c@2 as c, a@0 as a]",
+            "  InterleaveExec",
+            "    RepartitionExec: partitioning=Hash([a@0], 4), input_partitions=1",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    RepartitionExec: partitioning=Hash([a@0], 4), input_partitions=1",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "InterleaveExec",
+            "  RepartitionExec: partitioning=Hash([a@1], 4), input_partitions=1",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[c, a], has_header=false",
+            "  RepartitionExec: partitioning=Hash([a@1], 4), input_partitions=1",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[c, a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // The remapped hash partitioning must still be shared by all children:
+        assert_eq!(
+            after_optimize.output_partitioning().clone(),
+            Partitioning::Hash(vec![Arc::new(Column::new("a", 1))], 4),
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_interleave_after_projection_execution() -> Result<()> {
+        let partitioned_memory =
+            |a: Vec<i32>, b: Vec<i32>, c: Vec<i32>| -> Result<Arc<dyn ExecutionPlan>> {
+                Ok(Arc::new(RepartitionExec::try_new(
+                    create_i32_memory_exec(&[("a", a), ("b", b), ("c", c)])?,
+                    Partitioning::Hash(vec![Arc::new(Column::new("a", 0))], 2),
+                )?))
+            };
+        let interleave: Arc<dyn ExecutionPlan> =
+            Arc::new(InterleaveExec::try_new(vec![
+                partitioned_memory(vec![1, 2], vec![10, 20], vec![100, 200])?,
+                partitioned_memory(vec![3], vec![30], vec![300])?,
+            ])?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            interleave,
+        )?);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "InterleaveExec",
+            "  RepartitionExec: partitioning=Hash([a@1], 2), input_partitions=1",
+            "    MemoryExec: partitions=1, partition_sizes=[1]",
+            "  RepartitionExec: partitioning=Hash([a@1], 2), input_partitions=1",
+            "    MemoryExec: partitions=1, partition_sizes=[1]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        let batches = collect(after_optimize, Arc::new(TaskContext::default())).await?;
+        let expected = [
+            "+-----+---+",
+            "| c   | a |",
+            "+-----+---+",
+            "| 100 | 1 |",
+            "| 200 | 2 |",
+            "| 300 | 3 |",
+            "+-----+---+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_leaf_after_projection() -> Result<()> {
+        // A custom leaf, e.g. the scan of a user-defined table function, which
+        // can not embed projections:
+        let schema = Schema::new(
+            ["a", "b", "c", "d", "e"]
+                .into_iter()
+                .map(|name| Field::new(name, DataType::Int32, true))
+                .collect::<Vec<_>>(),
+        );
+        let leaf: Arc<dyn ExecutionPlan> = Arc::new(StatisticsExec::new(
+            Statistics::new_unknown(&schema),
+            schema,
+        ));
+        let coalesce_partitions: Arc<dyn ExecutionPlan> =
+            Arc::new(CoalescePartitionsExec::new(leaf));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+            ],
+            coalesce_partitions,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, d@3 as d]",
+            "  CoalescePartitionsExec",
+            "    StatisticsExec: col_count=5, row_count=Absent",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The projection is pushed as far as possible and stays right above
+        // the leaf, so everything above it only sees the two used columns:
+        let expected = [
+            "CoalescePartitionsExec",
+            "  ProjectionExec: expr=[b@1 as b, d@3 as d]",
+            "    StatisticsExec: col_count=5, row_count=Absent",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        assert_eq!(after_optimize.schema().fields().len(), 2);
+
+        Ok(())
+    }
+
+    /// A custom leaf that embeds projections of its columns through
+    /// [`ExecutionPlan::try_pushdown_projection`]
+    #[derive(Debug)]
+    struct ProjectableLeafExec {
+        schema: SchemaRef,
+        cache: PlanProperties,
+    }
+
+    impl ProjectableLeafExec {
+        fn new(schema: SchemaRef) -> Self {
+            let cache = PlanProperties::new(
+                EquivalenceProperties::new(schema.clone()),
+                Partitioning::UnknownPartitioning(2),
+                ExecutionMode::Bounded,
+            );
+            Self { schema, cache }
+        }
+    }
+
+    impl DisplayAs for ProjectableLeafExec {
+        fn fmt_as(
+            &self,
+            _t: DisplayFormatType,
+            f: &mut std::fmt::Formatter,
+        ) -> std::fmt::Result {
+            let names = self.schema.fields().iter().map(|field| field.name());
+            write!(
+                f,
+                "ProjectableLeafExec: projection=[{}]",
+                names.format(", ")
+            )
+        }
+    }
+
+    impl ExecutionPlan for ProjectableLeafExec {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn properties(&self) -> &PlanProperties {
+            &self.cache
+        }
+
+        fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+            vec![]
+        }
+
+        fn with_new_children(
+            self: Arc<Self>,
+            _: Vec<Arc<dyn ExecutionPlan>>,
+        ) -> Result<Arc<dyn ExecutionPlan>> {
+            Ok(self)
+        }
+
+        fn execute(
+            &self,
+            _partition: usize,
+            _context: Arc<TaskContext>,
+        ) -> Result<SendableRecordBatchStream> {
+            unimplemented!("This plan only serves for testing projection pushdown")
+        }
+
+        fn try_pushdown_projection(
+            &self,
+            projection: &ProjectionExec,
+        ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+            // Only plain column references without renaming can be embedded:
+            let indices = projection
+                .expr()
+                .iter()
+                .map(|(expr, alias)| {
+                    expr.as_any()
+                        .downcast_ref::<Column>()
+                        .filter(|col| col.name() == alias)
+                        .map(|col| col.index())
+                })
+                .collect::<Option<Vec<_>>>();
+            let Some(indices) = indices else {
+                return Ok(None);
+            };
+            let schema = self.schema.project(&indices)?;
+            Ok(Some(Arc::new(Self::new(Arc::new(schema)))))
+        }
+    }
+
+    #[test]
+    fn test_custom_leaf_embeds_projection() -> Result<()> {
+        let schema = Arc::new(Schema::new(
+            ["a", "b", "c", "d", "e"]
+                .into_iter()
+                .map(|name| Field::new(name, DataType::Int32, true))
+                .collect::<Vec<_>>(),
+        ));
+        let leaf: Arc<dyn ExecutionPlan> = Arc::new(ProjectableLeafExec::new(schema));
+        let coalesce_partitions: Arc<dyn ExecutionPlan> =
+            Arc::new(CoalescePartitionsExec::new(leaf));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+            ],
+            coalesce_partitions.clone(),
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, d@3 as d]",
+            "  CoalescePartitionsExec",
+            "    ProjectableLeafExec: projection=[a, b, c, d, e]",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The leaf opts in, so the projection is embedded into it:
+        let expected = [
+            "CoalescePartitionsExec",
+            "  ProjectableLeafExec: projection=[b, d]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // A renaming projection is not supported by the leaf and stays:
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("b", 1)), "new_b".to_string())],
+            coalesce_partitions,
+        )?);
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+        let expected = [
+            "CoalescePartitionsExec",
+            "  ProjectionExec: expr=[b@1 as new_b]",
+            "    ProjectableLeafExec: projection=[a, b, c, d, e]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_placeholder_row_after_projection() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Int32, true),
+        ]));
+        let placeholder: Arc<dyn ExecutionPlan> =
+            Arc::new(PlaceholderRowExec::new(schema));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![(Arc::new(Column::new("b", 1)), "b".to_string())],
+            placeholder,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial =
+            ["ProjectionExec: expr=[b@1 as b]", "  PlaceholderRowExec"];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = ["PlaceholderRowExec"];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        assert_eq!(
+            after_optimize.schema(),
+            Arc::new(Schema::new(vec![Field::new("b", DataType::Int32, true)]))
+        );
+
+        // The narrowed plan must still produce exactly one row:
+        let batches = collect(after_optimize, Arc::new(TaskContext::default())).await?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].num_rows(), 1);
+        assert_eq!(batches[0].num_columns(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_projection_below_root_sort_preserving_merge() -> Result<()> {
+        let csv = create_multi_partition_csv_exec();
+        let sort: Arc<dyn ExecutionPlan> = Arc::new(
+            SortExec::new(
+                vec![PhysicalSortExpr {
+                    expr: Arc::new(Column::new("b", 1)),
+                    options: SortOptions::default(),
+                }],
+                csv,
+            )
+            .with_preserve_partitioning(true),
+        );
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            sort,
+        )?);
+        let spm: Arc<dyn ExecutionPlan> = Arc::new(SortPreservingMergeExec::new(
+            vec![PhysicalSortExpr {
+                expr: Arc::new(Column::new("b", 0)),
+                options: SortOptions::default(),
+            }],
+            projection,
+        ));
+        let initial = get_plan_string(&spm);
+        let expected_initial = [
+            "SortPreservingMergeExec: [b@0 ASC]",
+            "  ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "    SortExec: expr=[b@1 ASC]",
+            "      CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let schema_before = spm.schema();
+        let after_optimize =
+            ProjectionPushdown::new().optimize(spm, &ConfigOptions::new())?;
+
+        // The root keeps its schema and sort key, while the columns it does
+        // not output are pruned from the scan:
+        let expected = [
+            "SortPreservingMergeExec: [b@0 ASC]",
+            "  SortExec: expr=[b@0 ASC]",
+            "    CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[b, a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        assert_eq!(after_optimize.schema(), schema_before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_spm_over_order_preserving_repartition_after_projection() -> Result<()> {
+        let csv = create_multi_partition_csv_exec();
+        let sort_exprs = vec![PhysicalSortExpr {
+            expr: Arc::new(Column::new("b", 1)),
+            options: SortOptions::default(),
+        }];
+        let sort: Arc<dyn ExecutionPlan> = Arc::new(
+            SortExec::new(sort_exprs.clone(), csv).with_preserve_partitioning(true),
+        );
+        let repartition: Arc<dyn ExecutionPlan> = Arc::new(
+            RepartitionExec::try_new(
+                sort,
+                Partitioning::Hash(vec![Arc::new(Column::new("b", 1))], 4),
+            )?
+            .with_preserve_order(),
+        );
+        let spm: Arc<dyn ExecutionPlan> =
+            Arc::new(SortPreservingMergeExec::new(sort_exprs, repartition));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+            ],
+            spm,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, b@1 as b]",
+            "  SortPreservingMergeExec: [b@1 ASC]",
+            "    RepartitionExec: partitioning=Hash([b@1], 4), input_partitions=2, preserve_order=true, sort_exprs=b@1 ASC",
+            "      SortExec: expr=[b@1 ASC]",
+            "        CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SortPreservingMergeExec: [b@1 ASC]",
+            "  RepartitionExec: partitioning=Hash([b@1], 4), input_partitions=2, preserve_order=true, sort_exprs=b@1 ASC",
+            "    SortExec: expr=[b@1 ASC]",
+            "      CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dictionary_column_after_projection() -> Result<()> {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", dict_type.clone(), true),
+            Field::new("c", DataType::Int32, true),
+        ]));
+        let csv: Arc<dyn ExecutionPlan> = Arc::new(CsvExec::new(
+            FileScanConfig {
+                object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+                file_schema: schema.clone(),
+                file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
+                statistics: Statistics::new_unknown(&schema),
+                projection: Some(vec![0, 1, 2]),
+                limit: None,
+                table_partition_cols: vec![],
+                output_ordering: vec![vec![]],
+            },
+            false,
+            0,
+            0,
+            None,
+            FileCompressionType::UNCOMPRESSED,
+        ));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            csv,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "CsvExec: file_groups={1 group: [[x]]}, projection=[b, a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        // The embedded projection must not decode the dictionary column:
+        assert_eq!(after_optimize.schema().field(0).data_type(), &dict_type);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_with_different_nullability_after_projection() -> Result<()> {
+        let memory_with_nullability = |nullable: bool| -> Result<Arc<dyn ExecutionPlan>> {
+            let schema = Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int32, nullable),
+                Field::new("b", DataType::Int32, true),
+                Field::new("c", DataType::Int32, true),
+            ]));
+            Ok(Arc::new(MemoryExec::try_new(&[], schema, None)?))
+        };
+        let union: Arc<dyn ExecutionPlan> = Arc::new(UnionExec::new(vec![
+            memory_with_nullability(false)?,
+            memory_with_nullability(true)?,
+        ]));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+            ],
+            union,
+        )?);
+        let schema_before = projection.schema();
+        assert!(schema_before.field(0).is_nullable());
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, c@2 as c]",
+            "  UnionExec",
+            "    MemoryExec: partitions=0, partition_sizes=[]",
+            "    MemoryExec: partitions=0, partition_sizes=[]",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "UnionExec",
+            "  MemoryExec: partitions=0, partition_sizes=[]",
+            "  MemoryExec: partitions=0, partition_sizes=[]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        // Each child keeps its own nullability, so the union output stays nullable:
+        assert_eq!(after_optimize.schema(), schema_before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_field_access_after_projection() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new(
+                "s",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("x", DataType::Int32, true),
+                    Field::new("z", DataType::Int32, true),
+                ])),
+                true,
+            ),
+            Field::new("y", DataType::Int32, true),
+        ]));
+        let memory: Arc<dyn ExecutionPlan> =
+            Arc::new(MemoryExec::try_new(&[], schema, None)?);
+        let sort: Arc<dyn ExecutionPlan> = Arc::new(SortExec::new(
+            vec![PhysicalSortExpr {
+                expr: Arc::new(Column::new("a", 0)),
+                options: SortOptions::default(),
+            }],
+            memory,
+        ));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (
+                    Arc::new(GetIndexedFieldExpr::new_field(
+                        Arc::new(Column::new("s", 1)),
+                        "x",
+                    )),
+                    "s_x".to_string(),
+                ),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            sort,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[(s@1).[x] as s_x, a@0 as a]",
+            "  SortExec: expr=[a@0 ASC]",
+            "    MemoryExec: partitions=0, partition_sizes=[]",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The field access keeps referring to the struct column `s`, while the
+        // unused sibling `y` is dropped below the sort:
+        let expected = [
+            "SortExec: expr=[a@1 ASC]",
+            "  ProjectionExec: expr=[(s@1).[x] as s_x, a@0 as a]",
+            "    MemoryExec: partitions=0, partition_sizes=[]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_four_consecutive_projections_unified() -> Result<()> {
+        let csv = create_simple_csv_exec();
+        let first: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("a", 0)),
+                        Operator::Plus,
+                        Arc::new(Column::new("b", 1)),
+                    )),
+                    "x".to_string(),
+                ),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+            ],
+            csv,
+        )?);
+        let second: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("x", 0)), "x".to_string()),
+                (Arc::new(Column::new("d", 2)), "d".to_string()),
+            ],
+            first,
+        )?);
+        let third: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("d", 1)), "d2".to_string()),
+                (Arc::new(Column::new("x", 0)), "x2".to_string()),
+            ],
+            second,
+        )?);
+        let fourth: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("x2", 1)), "y".to_string()),
+                (Arc::new(Column::new("d2", 0)), "z".to_string()),
+            ],
+            third,
+        )?);
+
+        let initial = get_plan_string(&fourth);
+        let expected_initial = [
+            "ProjectionExec: expr=[x2@1 as y, d2@0 as z]",
+            "  ProjectionExec: expr=[d@1 as d2, x@0 as x2]",
+            "    ProjectionExec: expr=[x@0 as x, d@2 as d]",
+            "      ProjectionExec: expr=[a@0 + b@1 as x, c@2 as c, d@3 as d]",
+            "        CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(fourth.clone(), &ConfigOptions::new())?;
+
+        let expected = [
+            "ProjectionExec: expr=[a@0 + b@1 as y, d@3 as z]",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // A single unification step covers the whole run, instead of merging
+        // one pair of projections at a time:
+        let top = fourth.as_any().downcast_ref::<ProjectionExec>().unwrap();
+        let child = top
+            .input()
+            .as_any()
+            .downcast_ref::<ProjectionExec>()
+            .unwrap();
+        let unified = try_unifying_projections(top, child)?.unwrap();
+        assert_eq!(get_plan_string(&unified), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_inverse_permutation_projections_removed() -> Result<()> {
+        let memory = create_i32_memory_exec(&[("a", vec![1, 2]), ("b", vec![3, 4])])?;
+        let swap = |input: Arc<dyn ExecutionPlan>| -> Result<Arc<dyn ExecutionPlan>> {
+            Ok(Arc::new(ProjectionExec::try_new(
+                vec![
+                    (Arc::new(Column::new("b", 1)), "b".to_string()),
+                    (Arc::new(Column::new("a", 0)), "a".to_string()),
+                ],
+                input,
+            )?))
+        };
+        let projection = swap(swap(memory.clone())?)?;
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "  ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "    MemoryExec: partitions=1, partition_sizes=[1]",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The unified projection is the identity, so both projections are gone:
+        let expected = ["MemoryExec: partitions=1, partition_sizes=[1]"];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        assert_eq!(after_optimize.schema(), memory.schema());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_preserving_sort_after_projection() -> Result<()> {
+        let csv = create_multi_partition_csv_exec();
+        let sort: Arc<dyn ExecutionPlan> = Arc::new(
+            SortExec::new(
+                vec![PhysicalSortExpr {
+                    expr: Arc::new(Column::new("c", 2)),
+                    options: SortOptions::default(),
+                }],
+                csv,
+            )
+            .with_preserve_partitioning(true),
+        );
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            sort,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[c@2 as c, a@0 as a]",
+            "  SortExec: expr=[c@2 ASC]",
+            "    CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SortExec: expr=[c@0 ASC]",
+            "  CsvExec: file_groups={2 groups: [[x], [y]]}, projection=[c, a], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // Pushing the projection down does not change the partitioning of the
+        // sort input, so the sort still preserves its two partitions:
+        let new_sort = after_optimize.as_any().downcast_ref::<SortExec>().unwrap();
+        assert!(new_sort.preserve_partitioning());
+        assert_eq!(new_sort.input().output_partitioning().partition_count(), 2);
+        assert_eq!(after_optimize.output_partitioning().partition_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_join_with_unused_single_row_side() -> Result<()> {
+        let left_csv = create_simple_csv_exec();
+        let right_schema = Arc::new(Schema::new(vec![
+            Field::new("x", DataType::Int32, true),
+            Field::new("y", DataType::Int32, true),
+        ]));
+        let right: Arc<dyn ExecutionPlan> =
+            Arc::new(PlaceholderRowExec::new(right_schema));
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(CrossJoinExec::new(left_csv, right));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+            ],
+            join,
+        )?);
+        let schema_before = projection.schema();
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, c@2 as c]",
+            "  CrossJoinExec",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    PlaceholderRowExec",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // The right side produces exactly one row, so the join does not change
+        // the rows of the left side and is removed altogether:
+        let expected = [
+            "CsvExec: file_groups={1 group: [[x]]}, projection=[a, c], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        assert_eq!(after_optimize.schema(), schema_before);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_skip_only_limit_chain_after_projection() -> Result<()> {
+        let memory = create_i32_memory_exec(&[
+            ("a", (1..=8).collect()),
+            ("b", (1..=8).map(|i| i * 10).collect()),
+            ("c", vec![0; 8]),
+        ])?;
+        let local_limit: Arc<dyn ExecutionPlan> =
+            Arc::new(LocalLimitExec::new(memory, 6));
+        let coalesce: Arc<dyn ExecutionPlan> =
+            Arc::new(CoalescePartitionsExec::new(local_limit));
+        // Skips rows without a fetch limit, so the local limit alone bounds
+        // the output:
+        let global_limit: Arc<dyn ExecutionPlan> =
+            Arc::new(GlobalLimitExec::new(coalesce, 3, None));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (
+                    Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new("a", 0)),
+                        Operator::Plus,
+                        Arc::new(Column::new("b", 1)),
+                    )),
+                    "a_plus_b".to_string(),
+                ),
+            ],
+            global_limit,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, a@0 + b@1 as a_plus_b]",
+            "  GlobalLimitExec: skip=3, fetch=None",
+            "    CoalescePartitionsExec",
+            "      LocalLimitExec: fetch=6",
+            "        MemoryExec: partitions=1, partition_sizes=[1]",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize = ProjectionPushdown::new()
+            .optimize(projection.clone(), &ConfigOptions::new())?;
+
+        // The projection computes an expression, so it can not be embedded
+        // into the scan and stops right above it:
+        let expected = [
+            "GlobalLimitExec: skip=3, fetch=None",
+            "  CoalescePartitionsExec",
+            "    LocalLimitExec: fetch=6",
+            "      ProjectionExec: expr=[b@1 as b, a@0 + b@1 as a_plus_b]",
+            "        MemoryExec: partitions=1, partition_sizes=[1]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // Both plans skip the same rows of the limited input:
+        let expected = [
+            "+----+----------+",
+            "| b  | a_plus_b |",
+            "+----+----------+",
+            "| 40 | 44       |",
+            "| 50 | 55       |",
+            "| 60 | 66       |",
+            "+----+----------+",
+        ];
+        let task_ctx = Arc::new(TaskContext::default());
+        assert_batches_eq!(expected, &collect(projection, task_ctx.clone()).await?);
+        assert_batches_eq!(expected, &collect(after_optimize, task_ctx).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_interleaving_projection_over_hash_join() -> Result<()> {
+        let left = create_i32_memory_exec(&[
+            ("a", vec![1, 2]),
+            ("b", vec![10, 20]),
+            ("x", vec![7, 8]),
+        ])?;
+        let right = create_i32_memory_exec(&[
+            ("c", vec![1, 2]),
+            ("d", vec![100, 200]),
+            ("y", vec![9, 9]),
+        ])?;
+        let join: Arc<dyn ExecutionPlan> = Arc::new(HashJoinExec::try_new(
+            left,
+            right,
+            vec![(Arc::new(Column::new("a", 0)), Arc::new(Column::new("c", 0)))],
+            None,
+            &JoinType::Inner,
+            PartitionMode::CollectLeft,
+            false,
+        )?);
+        // Left and right columns are interleaved in the output:
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("c", 3)), "c".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("d", 4)), "d".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[a@0 as a, c@3 as c, b@1 as b, d@4 as d]",
+            "  HashJoinExec: mode=CollectLeft, join_type=Inner, on=[(a@0, c@0)]",
+            "    MemoryExec: partitions=1, partition_sizes=[1]",
+            "    MemoryExec: partitions=1, partition_sizes=[1]",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        // Interleaved columns can not be split into a left and a right
+        // projection, so the projection stays above the join:
+        assert_eq!(get_plan_string(&after_optimize), expected_initial);
+
+        let batches = collect(after_optimize, Arc::new(TaskContext::default())).await?;
+        let expected = [
+            "+---+---+----+-----+",
+            "| a | c | b  | d   |",
+            "+---+---+----+-----+",
+            "| 1 | 1 | 10 | 100 |",
+            "| 2 | 2 | 20 | 200 |",
+            "+---+---+----+-----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_merge_join_with_sorted_children_after_projection() -> Result<()> {
+        let sort_options = vec![
+            SortOptions::default(),
+            SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        ];
+        let sorted_csv = |first: (&str, usize), second: (&str, usize)| {
+            Arc::new(SortExec::new(
+                vec![
+                    PhysicalSortExpr {
+                        expr: Arc::new(Column::new(first.0, first.1)),
+                        options: sort_options[0],
+                    },
+                    PhysicalSortExpr {
+                        expr: Arc::new(Column::new(second.0, second.1)),
+                        options: sort_options[1],
+                    },
+                ],
+                create_simple_csv_exec(),
+            )) as Arc<dyn ExecutionPlan>
+        };
+
+        let join: Arc<dyn ExecutionPlan> = Arc::new(SortMergeJoinExec::try_new(
+            sorted_csv(("b", 1), ("d", 3)),
+            sorted_csv(("c", 2), ("e", 4)),
+            vec![
+                (Arc::new(Column::new("b", 1)), Arc::new(Column::new("c", 2))),
+                (Arc::new(Column::new("d", 3)), Arc::new(Column::new("e", 4))),
+            ],
+            None,
+            JoinType::Inner,
+            sort_options.clone(),
+            false,
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("d", 3)), "d".to_string()),
+                (Arc::new(Column::new("c", 7)), "c".to_string()),
+                (Arc::new(Column::new("e", 9)), "e".to_string()),
+            ],
+            join,
+        )?);
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[b@1 as b, d@3 as d, c@7 as c, e@9 as e]",
+            "  SortMergeJoin: join_type=Inner, on=[(b@1, c@2), (d@3, e@4)]",
+            "    SortExec: expr=[b@1 ASC,d@3 DESC NULLS LAST]",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    SortExec: expr=[c@2 ASC,e@4 DESC NULLS LAST]",
+            "      CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "SortMergeJoin: join_type=Inner, on=[(b@0, c@0), (d@1, e@1)]",
+            "  SortExec: expr=[b@0 ASC,d@1 DESC NULLS LAST]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[b, d], has_header=false",
+            "  SortExec: expr=[c@0 ASC,e@1 DESC NULLS LAST]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[c, e], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // Both children must still satisfy the ordering required on the
+        // remapped join keys:
+        let children = after_optimize.children();
+        for (child, requirement) in children
+            .iter()
+            .zip(after_optimize.required_input_ordering())
+        {
+            let requirement = requirement.unwrap();
+            assert!(child
+                .equivalence_properties()
+                .ordering_satisfy_requirement(&requirement));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_partitioned_csv_after_projection() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Int32, true),
+        ]));
+        let csv: Arc<dyn ExecutionPlan> = Arc::new(CsvExec::new(
+            FileScanConfig {
+                object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+                file_schema: schema.clone(),
+                file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
+                statistics: Statistics::new_unknown(&schema),
+                projection: None,
+                limit: None,
+                table_partition_cols: vec![Field::new("part", DataType::Utf8, false)],
+                output_ordering: vec![vec![]],
+            },
+            false,
+            0,
+            0,
+            None,
+            FileCompressionType::UNCOMPRESSED,
+        ));
+        // The partition column is reordered before a data column:
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("part", 3)), "part".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+            ],
+            csv,
+        )?);
+        let schema_before = projection.schema();
+
+        let initial = get_plan_string(&projection);
+        let expected_initial = [
+            "ProjectionExec: expr=[part@3 as part, b@1 as b]",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, part], has_header=false",
+        ];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = [
+            "CsvExec: file_groups={1 group: [[x]]}, projection=[part, b], has_header=false",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+        assert_eq!(after_optimize.schema(), schema_before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_after_projection() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+            Field::new("c", DataType::Int64, true),
+        ]));
+        let literal = |value: i64| -> Arc<dyn PhysicalExpr> {
+            Arc::new(Literal::new(ScalarValue::Int64(Some(value))))
+        };
+        let values: Arc<dyn ExecutionPlan> = Arc::new(ValuesExec::try_new(
+            schema,
+            vec![
+                vec![literal(1), literal(2), literal(3)],
+                vec![literal(4), literal(5), literal(6)],
+            ],
+        )?);
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("c", 2)), "c".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            values,
+        )?);
+
+        let initial = get_plan_string(&projection);
+        let expected_initial =
+            ["ProjectionExec: expr=[c@2 as c, a@0 as a]", "  ValuesExec"];
+        assert_eq!(initial, expected_initial);
+
+        let after_optimize =
+            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+
+        let expected = ["ValuesExec"];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        let new_values = after_optimize
+            .as_any()
+            .downcast_ref::<ValuesExec>()
+            .unwrap();
+        let expected_batches = [
+            "+---+---+",
+            "| c | a |",
+            "+---+---+",
+            "| 3 | 1 |",
+            "| 6 | 4 |",
+            "+---+---+",
+        ];
+        assert_batches_sorted_eq!(expected_batches, &new_values.data());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_with_same_column_names_after_projection() -> Result<()> {
+        let join: Arc<dyn ExecutionPlan> = Arc::new(CrossJoinExec::new(
+            create_simple_csv_exec(),
+            create_simple_csv_exec(),
+        ));
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
                 (Arc::new(Column::new("a", 0)), "a".to_string()),
                 (Arc::new(Column::new("b", 1)), "b".to_string()),
-                (Arc::new(Column::new("c", 2)), "c".to_string()),
-                (Arc::new(Column::new("d", 3)), "d".to_string()),
-                (Arc::new(Column::new("e", 4)), "e".to_string()),
+                (Arc::new(Column::new("a", 5)), "a".to_string()),
+                (Arc::new(Column::new("b", 6)), "b".to_string()),
             ],
             join,
         )?);
+        let schema_before = projection.schema();
+
         let initial = get_plan_string(&projection);
         let expected_initial = [
-            "ProjectionExec: expr=[a@5 as a, b@6 as b, c@7 as c, d@8 as d, e@9 as e, a@0 as a, b@1 as b, c@2 as c, d@3 as d, e@4 as e]", 
-            "  SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(b@1, c@2)], filter=b_left_inter@0 - 1 + a_right_inter@1 <= a_right_inter@1 + c_left_inter@2", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
+            "ProjectionExec: expr=[a@0 as a, b@1 as b, a@5 as a, b@6 as b]",
+            "  CrossJoinExec",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
             "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
-            ];
+        ];
         assert_eq!(initial, expected_initial);
 
         let after_optimize =
             ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
 
+        // Each pushed projection only covers its own side, so the repeated
+        // names do not collide below the join:
         let expected = [
-            "ProjectionExec: expr=[a@5 as a, b@6 as b, c@7 as c, d@8 as d, e@9 as e, a@0 as a, b@1 as b, c@2 as c, d@3 as d, e@4 as e]", 
-            "  SymmetricHashJoinExec: mode=SinglePartition, join_type=Inner, on=[(b@1, c@2)], filter=b_left_inter@0 - 1 + a_right_inter@1 <= a_right_inter@1 + c_left_inter@2", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
-            ];
+            "CrossJoinExec",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a, b], has_header=false"
+        ];
         assert_eq!(get_plan_string(&after_optimize), expected);
+        assert_eq!(after_optimize.schema(), schema_before);
+
         Ok(())
     }
 
     #[test]
-    fn test_repartition_after_projection() -> Result<()> {
-        let csv = create_simple_csv_exec();
-        let repartition: Arc<dyn ExecutionPlan> = Arc::new(RepartitionExec::try_new(
-            csv,
-            Partitioning::Hash(
-                vec![
-                    Arc::new(Column::new("a", 0)),
-                    Arc::new(Column::new("b", 1)),
-                    Arc::new(Column::new("d", 3)),
-                ],
-                6,
-            ),
+    fn test_nested_loop_join_without_filter_after_projection() -> Result<()> {
+        let join: Arc<dyn ExecutionPlan> = Arc::new(NestedLoopJoinExec::try_new(
+            create_simple_csv_exec(),
+            create_simple_csv_exec(),
+            None,
+            &JoinType::Inner,
         )?);
         let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
             vec![
-                (Arc::new(Column::new("b", 1)), "b_new".to_string()),
-                (Arc::new(Column::new("a", 0)), "a".to_string()),
-                (Arc::new(Column::new("d", 3)), "d_new".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("e", 4)), "e".to_string()),
+                (Arc::new(Column::new("a", 5)), "a".to_string()),
             ],
-            repartition,
+            join,
         )?);
         let initial = get_plan_string(&projection);
         let expected_initial = [
-                "ProjectionExec: expr=[b@1 as b_new, a@0 as a, d@3 as d_new]",
-                "  RepartitionExec: partitioning=Hash([a@0, b@1, d@3], 6), input_partitions=1",
-                "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "ProjectionExec: expr=[b@1 as b, e@4 as e, a@5 as a]",
+            "  NestedLoopJoinExec: join_type=Inner",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
         ];
         assert_eq!(initial, expected_initial);
 
         let after_optimize =
             ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
 
+        // Without a filter, nothing besides the projected columns is needed:
         let expected = [
-                "RepartitionExec: partitioning=Hash([a@1, b_new@0, d_new@2], 6), input_partitions=1", 
-                "  ProjectionExec: expr=[b@1 as b_new, a@0 as a, d@3 as d_new]", 
-                "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+            "NestedLoopJoinExec: join_type=Inner",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[b, e], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[a], has_header=false"
         ];
         assert_eq!(get_plan_string(&after_optimize), expected);
 
-        assert_eq!(
-            after_optimize
-                .as_any()
-                .downcast_ref::<RepartitionExec>()
-                .unwrap()
-                .partitioning()
-                .clone(),
-            Partitioning::Hash(
-                vec![
-                    Arc::new(Column::new("a", 1)),
-                    Arc::new(Column::new("b_new", 0)),
-                    Arc::new(Column::new("d_new", 2)),
-                ],
-                6,
-            ),
-        );
-
         Ok(())
     }
 
     #[test]
-    fn test_sort_after_projection() -> Result<()> {
-        let csv = create_simple_csv_exec();
-        let sort_req: Arc<dyn ExecutionPlan> = Arc::new(SortExec::new(
-            vec![
-                PhysicalSortExpr {
-                    expr: Arc::new(Column::new("b", 1)),
-                    options: SortOptions::default(),
-                },
-                PhysicalSortExpr {
-                    expr: Arc::new(BinaryExpr::new(
-                        Arc::new(Column::new("c", 2)),
-                        Operator::Plus,
-                        Arc::new(Column::new("a", 0)),
-                    )),
-                    options: SortOptions::default(),
-                },
-            ],
-            csv.clone(),
-        ));
+    fn test_nested_loop_join_build_side_after_projection() -> Result<()> {
+        let join: Arc<dyn ExecutionPlan> = Arc::new(NestedLoopJoinExec::try_new(
+            create_simple_csv_exec(),
+            create_simple_csv_exec(),
+            // c_left > d_right
+            Some(JoinFilter::new(
+                Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new("c_left_inter", 0)),
+                    Operator::Gt,
+                    Arc::new(Column::new("d_right_inter", 1)),
+                )),
+                vec![
+                    ColumnIndex {
+                        index: 2,
+                        side: JoinSide::Left,
+                    },
+                    ColumnIndex {
+                        index: 3,
+                        side: JoinSide::Right,
+                    },
+                ],
+                Schema::new(vec![
+                    Field::new("c_left_inter", DataType::Int32, true),
+                    Field::new("d_right_inter", DataType::Int32, true),
+                ]),
+            )),
+            &JoinType::Inner,
+        )?);
         let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
             vec![
                 (Arc::new(Column::new("c", 2)), "c".to_string()),
-                (Arc::new(Column::new("a", 0)), "new_a".to_string()),
-                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("d", 8)), "d".to_string()),
             ],
-            sort_req.clone(),
+            join,
         )?);
-
         let initial = get_plan_string(&projection);
         let expected_initial = [
-            "ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]",
-            "  SortExec: expr=[b@1 ASC,c@2 + a@0 ASC]",
+            "ProjectionExec: expr=[c@2 as c, d@8 as d]",
+            "  NestedLoopJoinExec: join_type=Inner, filter=c_left_inter@0 > d_right_inter@1",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
             "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
-            ];
+        ];
         assert_eq!(initial, expected_initial);
 
         let after_optimize =
             ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
 
+        // The buffered left (build) side only reads the single column it provides:
         let expected = [
-            "SortExec: expr=[b@2 ASC,c@0 + new_a@1 ASC]",
-            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]",
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            "NestedLoopJoinExec: join_type=Inner, filter=c_left_inter@0 > d_right_inter@1",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[c], has_header=false",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[d], has_header=false"
         ];
         assert_eq!(get_plan_string(&after_optimize), expected);
 
+        let nl_join = after_optimize
+            .as_any()
+            .downcast_ref::<NestedLoopJoinExec>()
+            .unwrap();
+        assert_eq!(nl_join.left().schema().fields().len(), 1);
+        assert_eq!(
+            nl_join.filter().unwrap().column_indices(),
+            &[
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Left,
+                },
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Right,
+                },
+            ]
+        );
+
         Ok(())
     }
 
     #[test]
-    fn test_sort_preserving_after_projection() -> Result<()> {
-        let csv = create_simple_csv_exec();
-        let sort_req: Arc<dyn ExecutionPlan> = Arc::new(SortPreservingMergeExec::new(
+    fn test_projection_removal_with_duplicate_field_names() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("a", DataType::Int32, true),
+        ]));
+        let coalesce: Arc<dyn ExecutionPlan> = Arc::new(CoalescePartitionsExec::new(
+            Arc::new(MemoryExec::try_new(&[], schema, None)?),
+        ));
+
+        // Selecting every column at its own position is a no-op, even with
+        // repeated names:
+        let identity: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
             vec![
-                PhysicalSortExpr {
-                    expr: Arc::new(Column::new("b", 1)),
-                    options: SortOptions::default(),
-                },
-                PhysicalSortExpr {
-                    expr: Arc::new(BinaryExpr::new(
-                        Arc::new(Column::new("c", 2)),
-                        Operator::Plus,
-                        Arc::new(Column::new("a", 0)),
-                    )),
-                    options: SortOptions::default(),
-                },
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 2)), "a".to_string()),
             ],
-            csv.clone(),
+            coalesce.clone(),
+        )?);
+        let after_optimize =
+            ProjectionPushdown::new().optimize(identity, &ConfigOptions::new())?;
+        let expected = [
+            "CoalescePartitionsExec",
+            "  MemoryExec: partitions=0, partition_sizes=[]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        // Swapping the two columns named `a` must not be mistaken for a no-op:
+        let swapped: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("a", 2)), "a".to_string()),
+                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
+            ],
+            coalesce,
+        )?);
+        let after_optimize =
+            ProjectionPushdown::new().optimize(swapped, &ConfigOptions::new())?;
+        let expected = [
+            "ProjectionExec: expr=[a@2 as a, b@1 as b, a@0 as a]",
+            "  CoalescePartitionsExec",
+            "    MemoryExec: partitions=0, partition_sizes=[]",
+        ];
+        assert_eq!(get_plan_string(&after_optimize), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_preserving_merge_on_expression_after_projection() -> Result<()> {
+        let spm: Arc<dyn ExecutionPlan> = Arc::new(SortPreservingMergeExec::new(
+            vec![PhysicalSortExpr {
+                expr: Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new("c", 2)),
+                    Operator::Plus,
+                    Arc::new(Column::new("e", 4)),
+                )),
+                options: SortOptions::default(),
+            }],
+            create_simple_csv_exec(),
         ));
+        // The column `d` between `c` and `e` is pruned:
         let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
             vec![
                 (Arc::new(Column::new("c", 2)), "c".to_string()),
-                (Arc::new(Column::new("a", 0)), "new_a".to_string()),
-                (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("e", 4)), "e".to_string()),
             ],
-            sort_req.clone(),
+            spm,
         )?);
 
         let initial = get_plan_string(&projection);
         let expected_initial = [
-            "ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]",
-            "  SortPreservingMergeExec: [b@1 ASC,c@2 + a@0 ASC]",
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
-            ];
+            "ProjectionExec: expr=[c@2 as c, e@4 as e]",
+            "  SortPreservingMergeExec: [c@2 + e@4 ASC]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
         assert_eq!(initial, expected_initial);
 
         let after_optimize =
             ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
 
         let expected = [
-            "SortPreservingMergeExec: [b@2 ASC,c@0 + new_a@1 ASC]",
-            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]",
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            "SortPreservingMergeExec: [c@0 + e@1 ASC]",
+            "  CsvExec: file_groups={1 group: [[x]]}, projection=[c, e], has_header=false",
         ];
         assert_eq!(get_plan_string(&after_optimize), expected);
 
@@ -2410,40 +6577,38 @@ mod tests {
     }
 
     #[test]
-    fn test_union_after_projection() -> Result<()> {
+    fn test_removed_projection_exposes_pushdown_below() -> Result<()> {
         let csv = create_simple_csv_exec();
-        let union: Arc<dyn ExecutionPlan> =
-            Arc::new(UnionExec::new(vec![csv.clone(), csv.clone(), csv]));
-        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+        let narrowing: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
             vec![
-                (Arc::new(Column::new("c", 2)), "c".to_string()),
-                (Arc::new(Column::new("a", 0)), "new_a".to_string()),
                 (Arc::new(Column::new("b", 1)), "b".to_string()),
+                (Arc::new(Column::new("a", 0)), "a".to_string()),
             ],
-            union.clone(),
+            csv,
+        )?);
+        let identity: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (Arc::new(Column::new("b", 0)), "b".to_string()),
+                (Arc::new(Column::new("a", 1)), "a".to_string()),
+            ],
+            narrowing,
         )?);
 
-        let initial = get_plan_string(&projection);
+        let initial = get_plan_string(&identity);
         let expected_initial = [
-            "ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]", 
-            "  UnionExec", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
-            ];
+            "ProjectionExec: expr=[b@0 as b, a@1 as a]",
+            "  ProjectionExec: expr=[b@1 as b, a@0 as a]",
+            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false",
+        ];
         assert_eq!(initial, expected_initial);
 
         let after_optimize =
-            ProjectionPushdown::new().optimize(projection, &ConfigOptions::new())?;
+            ProjectionPushdown::new().optimize(identity, &ConfigOptions::new())?;
 
+        // Once the identity projection is removed, the projection below it is
+        // embedded into the scan as well:
         let expected = [
-            "UnionExec", 
-            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
-            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false", 
-            "  ProjectionExec: expr=[c@2 as c, a@0 as new_a, b@1 as b]", 
-            "    CsvExec: file_groups={1 group: [[x]]}, projection=[a, b, c, d, e], has_header=false"
+            "CsvExec: file_groups={1 group: [[x]]}, projection=[b, a], has_header=false",
         ];
         assert_eq!(get_plan_string(&after_optimize), expected);
 
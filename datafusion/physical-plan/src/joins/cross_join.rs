@@ -18,7 +18,7 @@
 //! Defines the cross join plan for loading the left side of the cross join
 //! and producing batches in parallel for the right partitions
 
-use std::{any::Any, sync::Arc, task::Poll};
+use std::{any::Any, collections::VecDeque, iter, ops::Range, sync::Arc, task::Poll};
 
 use super::utils::{
     adjust_right_output_partitioning, BuildProbeJoinMetrics, OnceAsync, OnceFut,
@@ -29,10 +29,12 @@ use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use crate::ExecutionPlanProperties;
 use crate::{
     execution_mode_from_children, ColumnStatistics, DisplayAs, DisplayFormatType,
-    Distribution, ExecutionMode, ExecutionPlan, PlanProperties, RecordBatchStream,
-    SendableRecordBatchStream, Statistics,
+    Distribution, EmptyRecordBatchStream, ExecutionMode, ExecutionPlan, PlanProperties,
+    RecordBatchStream, SendableRecordBatchStream, Statistics,
 };
 
+use arrow::array::{Array, UInt32Array};
+use arrow::compute::take;
 use arrow::datatypes::{Fields, Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use arrow_array::RecordBatchOptions;
@@ -50,6 +52,10 @@ type JoinLeftData = (RecordBatch, MemoryReservation);
 
 /// executes partitions in parallel and combines them into a set of
 /// partitions by combining all values from the left with all values on the right
+///
+/// If the statistics of the left side report exactly zero rows, `execute`
+/// returns an empty stream without executing either side. Errors of the right
+/// side are not surfaced in that case.
 #[derive(Debug)]
 pub struct CrossJoinExec {
     /// left (build) side which gets loaded in memory
@@ -62,6 +68,8 @@ pub struct CrossJoinExec {
     left_fut: OnceAsync<JoinLeftData>,
     /// Execution plan metrics
     metrics: ExecutionPlanMetricsSet,
+    /// Estimated upper bound on the memory size of each output batch
+    max_output_batch_bytes: Option<usize>,
     cache: PlanProperties,
 }
 
@@ -85,10 +93,27 @@ impl CrossJoinExec {
             schema,
             left_fut: Default::default(),
             metrics: ExecutionPlanMetricsSet::default(),
+            max_output_batch_bytes: None,
             cache,
         }
     }
 
+    /// Limits the estimated memory size of each output batch to `bytes`.
+    /// Each output batch combines a chunk of left rows with a batch from the
+    /// right side, and the left rows are split into chunks that fit into this
+    /// budget. If a single left row combined with a right batch exceeds the
+    /// budget, the right batch is split into smaller slices as well. Each
+    /// output batch still contains at least one row.
+    pub fn with_max_output_batch_bytes(mut self, bytes: usize) -> Self {
+        self.max_output_batch_bytes = Some(bytes);
+        self
+    }
+
+    /// Estimated upper bound on the memory size of each output batch, if any
+    pub fn max_output_batch_bytes(&self) -> Option<usize> {
+        self.max_output_batch_bytes
+    }
+
     /// left (build) side which gets loaded in memory
     pub fn left(&self) -> &Arc<dyn ExecutionPlan> {
         &self.left
@@ -186,9 +211,16 @@ impl DisplayAs for CrossJoinExec {
         f: &mut std::fmt::Formatter,
     ) -> std::fmt::Result {
         match t {
-            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+            DisplayFormatType::Default => {
                 write!(f, "CrossJoinExec")
             }
+            DisplayFormatType::Verbose => {
+                write!(f, "CrossJoinExec")?;
+                if let Some(bytes) = self.max_output_batch_bytes {
+                    write!(f, ": max_output_batch_bytes={bytes}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -214,10 +246,9 @@ impl ExecutionPlan for CrossJoinExec {
         self: Arc<Self>,
         children: Vec<Arc<dyn ExecutionPlan>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        Ok(Arc::new(CrossJoinExec::new(
-            children[0].clone(),
-            children[1].clone(),
-        )))
+        let mut join = CrossJoinExec::new(children[0].clone(), children[1].clone());
+        join.max_output_batch_bytes = self.max_output_batch_bytes;
+        Ok(Arc::new(join))
     }
 
     fn required_input_distribution(&self) -> Vec<Distribution> {
@@ -232,6 +263,18 @@ impl ExecutionPlan for CrossJoinExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
+        // If the left side is known to be empty, the join can not produce any
+        // rows, so neither side needs to be executed. Errors that the right
+        // side would have raised are suppressed in this case. Statistics are
+        // only a hint here, so their errors are ignored:
+        let left_is_empty = matches!(
+            self.left.statistics().map(|stats| stats.num_rows),
+            Ok(Precision::Exact(0))
+        );
+        if left_is_empty {
+            return Ok(Box::pin(EmptyRecordBatchStream::new(self.schema.clone())));
+        }
+
         let stream = self.right.execute(partition, context.clone())?;
 
         let join_metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
@@ -254,7 +297,10 @@ impl ExecutionPlan for CrossJoinExec {
             left_fut,
             right: stream,
             right_batch: Arc::new(parking_lot::Mutex::new(None)),
+            right_slices: VecDeque::new(),
+            left_rows_per_batch: 1,
             left_index: 0,
+            max_output_batch_bytes: self.max_output_batch_bytes,
             join_metrics,
         }))
     }
@@ -323,6 +369,13 @@ struct CrossJoinStream {
     left_index: usize,
     /// Current batch being processed from the right side
     right_batch: Arc<parking_lot::Mutex<Option<RecordBatch>>>,
+    /// Remaining slices of the last batch received from the right side
+    right_slices: VecDeque<RecordBatch>,
+    /// Number of left rows combined with the current right batch in each
+    /// output batch
+    left_rows_per_batch: usize,
+    /// Estimated upper bound on the memory size of each output batch
+    max_output_batch_bytes: Option<usize>,
     /// join execution metrics
     join_metrics: BuildProbeJoinMetrics,
 }
@@ -334,33 +387,120 @@ impl RecordBatchStream for CrossJoinStream {
 }
 
 fn build_batch(
-    left_index: usize,
+    left_rows: Range<usize>,
     batch: &RecordBatch,
     left_data: &RecordBatch,
     schema: &Schema,
 ) -> Result<RecordBatch> {
-    // Repeat value on the left n times
-    let arrays = left_data
-        .columns()
-        .iter()
-        .map(|arr| {
-            let scalar = ScalarValue::try_from_array(arr, left_index)?;
-            scalar.to_array_of_size(batch.num_rows())
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let num_left_rows = left_rows.len();
+    let (left_arrays, right_arrays) = if num_left_rows == 1 {
+        // Repeat value on the left n times
+        let left_arrays = left_data
+            .columns()
+            .iter()
+            .map(|arr| {
+                let scalar = ScalarValue::try_from_array(arr, left_rows.start)?;
+                scalar.to_array_of_size(batch.num_rows())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        (left_arrays, batch.columns().to_vec())
+    } else {
+        // Repeat every value on the left n times, and the right batch once per
+        // left row
+        let left_indices = UInt32Array::from_iter_values(
+            left_rows.flat_map(|index| iter::repeat(index as u32).take(batch.num_rows())),
+        );
+        let right_indices = UInt32Array::from_iter_values(
+            (0..num_left_rows).flat_map(|_| 0..batch.num_rows() as u32),
+        );
+        let left_arrays = left_data
+            .columns()
+            .iter()
+            .map(|arr| take(arr.as_ref(), &left_indices, None))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let right_arrays = batch
+            .columns()
+            .iter()
+            .map(|arr| take(arr.as_ref(), &right_indices, None))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        (left_arrays, right_arrays)
+    };
 
     RecordBatch::try_new_with_options(
         Arc::new(schema.clone()),
-        arrays
-            .iter()
-            .chain(batch.columns().iter())
-            .cloned()
-            .collect(),
-        &RecordBatchOptions::new().with_row_count(Some(batch.num_rows())),
+        left_arrays.into_iter().chain(right_arrays).collect(),
+        &RecordBatchOptions::new().with_row_count(Some(num_left_rows * batch.num_rows())),
     )
     .map_err(Into::into)
 }
 
+/// Like [`build_batch`], additionally recording the join time and the
+/// input/output metrics of the stream
+fn build_batch_with_metrics(
+    left_rows: Range<usize>,
+    batch: &RecordBatch,
+    left_data: &RecordBatch,
+    schema: &Schema,
+    join_metrics: &BuildProbeJoinMetrics,
+) -> Result<RecordBatch> {
+    let join_timer = join_metrics.join_time.timer();
+    let result = build_batch(left_rows, batch, left_data, schema);
+    join_metrics.input_rows.add(batch.num_rows());
+    if let Ok(ref batch) = result {
+        join_timer.done();
+        join_metrics.output_batches.add(1);
+        join_metrics.output_rows.add(batch.num_rows());
+    }
+    result
+}
+
+/// Returns the memory size of the values in `batch`, excluding buffer space
+/// that is shared with data outside of `batch` (e.g. for sliced batches).
+fn logical_memory_size(batch: &RecordBatch) -> usize {
+    batch
+        .columns()
+        .iter()
+        .map(|arr| {
+            arr.to_data()
+                .get_slice_memory_size()
+                .unwrap_or_else(|_| arr.get_array_memory_size())
+        })
+        .sum()
+}
+
+/// Decides how `batch` is combined with the rows of `left_data` such that each
+/// output batch is at most `max_bytes` (estimated) bytes. Returns the slices of
+/// `batch` to combine with the left rows, and the number of left rows to
+/// combine with a slice in each output batch.
+///
+/// Without a budget, every left row is combined with the whole `batch`. With a
+/// budget, the left rows are split into chunks that fit into the budget. Only
+/// if a single left row combined with the whole `batch` exceeds the budget,
+/// `batch` is split as well. Every output batch contains at least one row.
+fn split_for_budget(
+    batch: RecordBatch,
+    left_data: &RecordBatch,
+    max_bytes: Option<usize>,
+) -> (VecDeque<RecordBatch>, usize) {
+    let num_rows = batch.num_rows();
+    let Some(max_bytes) = max_bytes.filter(|_| num_rows > 0) else {
+        return (VecDeque::from([batch]), 1);
+    };
+
+    let left_row_bytes = logical_memory_size(left_data) / left_data.num_rows().max(1);
+    let right_row_bytes = logical_memory_size(&batch) / num_rows;
+    let rows_per_batch = (max_bytes / (left_row_bytes + right_row_bytes).max(1)).max(1);
+
+    if rows_per_batch >= num_rows {
+        return (VecDeque::from([batch]), rows_per_batch / num_rows);
+    }
+    let slices = (0..num_rows)
+        .step_by(rows_per_batch)
+        .map(|offset| batch.slice(offset, rows_per_batch.min(num_rows - offset)))
+        .collect();
+    (slices, 1)
+}
+
 #[async_trait]
 impl Stream for CrossJoinStream {
     type Item = Result<RecordBatch>;
@@ -392,38 +532,61 @@ impl CrossJoinStream {
         }
 
         if self.left_index > 0 && self.left_index < left_data.num_rows() {
-            let join_timer = self.join_metrics.join_time.timer();
             let right_batch = {
                 let right_batch = self.right_batch.lock();
                 right_batch.clone().unwrap()
             };
-            let result =
-                build_batch(self.left_index, &right_batch, left_data, &self.schema);
-            self.join_metrics.input_rows.add(right_batch.num_rows());
-            if let Ok(ref batch) = result {
-                join_timer.done();
-                self.join_metrics.output_batches.add(1);
-                self.join_metrics.output_rows.add(batch.num_rows());
-            }
-            self.left_index += 1;
+            let left_end =
+                (self.left_index + self.left_rows_per_batch).min(left_data.num_rows());
+            let result = build_batch_with_metrics(
+                self.left_index..left_end,
+                &right_batch,
+                left_data,
+                &self.schema,
+                &self.join_metrics,
+            );
+            self.left_index = left_end;
             return Poll::Ready(Some(result));
         }
         self.left_index = 0;
+        // Continue with the next slice of the last right batch, if any:
+        if let Some(right_slice) = self.right_slices.pop_front() {
+            let left_end = self.left_rows_per_batch.min(left_data.num_rows());
+            let result = build_batch_with_metrics(
+                0..left_end,
+                &right_slice,
+                left_data,
+                &self.schema,
+                &self.join_metrics,
+            );
+            self.left_index = left_end;
+
+            let mut right_batch = self.right_batch.lock();
+            *right_batch = Some(right_slice);
+
+            return Poll::Ready(Some(result));
+        }
         self.right
             .poll_next_unpin(cx)
             .map(|maybe_batch| match maybe_batch {
                 Some(Ok(batch)) => {
-                    let join_timer = self.join_metrics.join_time.timer();
-                    let result =
-                        build_batch(self.left_index, &batch, left_data, &self.schema);
                     self.join_metrics.input_batches.add(1);
-                    self.join_metrics.input_rows.add(batch.num_rows());
-                    if let Ok(ref batch) = result {
-                        join_timer.done();
-                        self.join_metrics.output_batches.add(1);
-                        self.join_metrics.output_rows.add(batch.num_rows());
-                    }
-                    self.left_index = 1;
+                    let (mut slices, left_rows_per_batch) =
+                        split_for_budget(batch, left_data, self.max_output_batch_bytes);
+                    // There is always at least one slice:
+                    let batch = slices.pop_front().unwrap();
+                    self.right_slices = slices;
+                    self.left_rows_per_batch = left_rows_per_batch;
+
+                    let left_end = left_rows_per_batch.min(left_data.num_rows());
+                    let result = build_batch_with_metrics(
+                        0..left_end,
+                        &batch,
+                        left_data,
+                        &self.schema,
+                        &self.join_metrics,
+                    );
+                    self.left_index = left_end;
 
                     let mut right_batch = self.right_batch.lock();
                     *right_batch = Some(batch);
@@ -438,11 +601,20 @@ impl CrossJoinStream {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common;
-    use crate::test::build_table_scan_i32;
-
-    use datafusion_common::{assert_batches_sorted_eq, assert_contains};
+    use crate::memory::MemoryExec;
+    use crate::test::exec::{
+        assert_strong_count_converges_to_zero, BlockingExec, ErrorExec, MockExec,
+    };
+    use crate::test::{assert_is_pending, build_table_i32, build_table_scan_i32};
+    use crate::union::UnionExec;
+    use crate::{collect, common, displayable};
+
+    use arrow::datatypes::{DataType, Field};
+    use arrow_array::Int32Array;
+    use datafusion_common::cast::as_int32_array;
+    use datafusion_common::{assert_batches_sorted_eq, assert_contains, exec_err};
     use datafusion_execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+    use futures::FutureExt;
 
     async fn join_collect(
         left: Arc<dyn ExecutionPlan>,
@@ -589,6 +761,60 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[tokio::test]
+    async fn test_stats_cartesian_product_with_inexact_size() {
+        let left_row_count = 11;
+        let left_bytes = 23;
+        let right_row_count = 7;
+        let right_bytes = 27;
+
+        let left = Statistics {
+            num_rows: Precision::Inexact(left_row_count),
+            total_byte_size: Precision::Inexact(left_bytes),
+            column_statistics: vec![ColumnStatistics {
+                distinct_count: Precision::Exact(5),
+                max_value: Precision::Exact(ScalarValue::Int64(Some(21))),
+                min_value: Precision::Exact(ScalarValue::Int64(Some(-4))),
+                null_count: Precision::Exact(1),
+            }],
+        };
+
+        let right = Statistics {
+            num_rows: Precision::Inexact(right_row_count),
+            total_byte_size: Precision::Inexact(right_bytes),
+            column_statistics: vec![ColumnStatistics {
+                distinct_count: Precision::Exact(3),
+                max_value: Precision::Exact(ScalarValue::Int64(Some(12))),
+                min_value: Precision::Exact(ScalarValue::Int64(Some(0))),
+                null_count: Precision::Exact(2),
+            }],
+        };
+
+        let result = stats_cartesian_product(left, right);
+
+        let expected = Statistics {
+            num_rows: Precision::Inexact(left_row_count * right_row_count),
+            total_byte_size: Precision::Inexact(2 * left_bytes * right_bytes),
+            column_statistics: vec![
+                ColumnStatistics {
+                    distinct_count: Precision::Exact(5),
+                    max_value: Precision::Exact(ScalarValue::Int64(Some(21))),
+                    min_value: Precision::Exact(ScalarValue::Int64(Some(-4))),
+                    // an exact null count times an inexact row count is inexact
+                    null_count: Precision::Inexact(right_row_count),
+                },
+                ColumnStatistics {
+                    distinct_count: Precision::Exact(3),
+                    max_value: Precision::Exact(ScalarValue::Int64(Some(12))),
+                    min_value: Precision::Exact(ScalarValue::Int64(Some(0))),
+                    null_count: Precision::Inexact(2 * left_row_count),
+                },
+            ],
+        };
+
+        assert_eq!(result, expected);
+    }
+
     #[tokio::test]
     async fn test_join() -> Result<()> {
         let task_ctx = Arc::new(TaskContext::default());
@@ -654,6 +880,222 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_drop_cancel() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+
+        let left = build_table_scan_i32(
+            ("a1", &vec![1, 2, 3]),
+            ("b1", &vec![4, 5, 6]),
+            ("c1", &vec![7, 8, 9]),
+        );
+        let blocking_exec = Arc::new(BlockingExec::new(left.schema(), 1));
+        let refs = blocking_exec.refs();
+        // The build side never completes loading, since one of its partitions
+        // blocks forever:
+        let left = Arc::new(UnionExec::new(vec![left, blocking_exec as _]));
+        let right = build_table_scan_i32(
+            ("a2", &vec![10, 11]),
+            ("b2", &vec![12, 13]),
+            ("c2", &vec![14, 15]),
+        );
+        let join = Arc::new(CrossJoinExec::new(left, right));
+
+        let fut = collect(join, task_ctx.clone());
+        let mut fut = fut.boxed();
+
+        assert_is_pending(&mut fut);
+        drop(fut);
+        assert_strong_count_converges_to_zero(refs).await;
+
+        assert_eq!(
+            task_ctx.runtime_env().memory_pool.reserved(),
+            0,
+            "The cross join should have returned all memory used back to the memory manager"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_right_stream_error() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+
+        let left = build_table_scan_i32(
+            ("a1", &vec![1, 2, 3]),
+            ("b1", &vec![4, 5, 6]),
+            ("c1", &vec![7, 8, 9]),
+        );
+        let right_batch = build_table_i32(
+            ("a2", &vec![10, 11]),
+            ("b2", &vec![12, 13]),
+            ("c2", &vec![14, 15]),
+        );
+        let right_schema = right_batch.schema();
+        // The right side fails after its first batch, i.e. once the left side
+        // has already been loaded:
+        let right = Arc::new(MockExec::new(
+            vec![Ok(right_batch), exec_err!("bad data error")],
+            right_schema,
+        ));
+        let join = Arc::new(CrossJoinExec::new(left, right));
+
+        let err = collect(join, task_ctx.clone()).await.unwrap_err();
+        assert_contains!(err.to_string(), "bad data error");
+
+        assert_eq!(
+            task_ctx.runtime_env().memory_pool.reserved(),
+            0,
+            "The cross join should have returned all memory used back to the memory manager"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_empty_left_does_not_execute_right() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+
+        let left =
+            build_table_scan_i32(("a1", &vec![]), ("b1", &vec![]), ("c1", &vec![]));
+        // The right side errors as soon as it is executed:
+        let right = Arc::new(ErrorExec::new());
+        let join = Arc::new(CrossJoinExec::new(left, right));
+
+        let batches = collect(join, task_ctx).await?;
+        assert!(batches.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_max_output_batch_bytes() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+
+        let left = build_table_scan_i32(
+            ("a1", &vec![1, 2, 3]),
+            ("b1", &vec![4, 5, 6]),
+            ("c1", &vec![7, 8, 9]),
+        );
+        let right = build_table_scan_i32(
+            ("a2", &vec![10, 11, 12, 13]),
+            ("b2", &vec![14, 15, 16, 17]),
+            ("c2", &vec![18, 19, 20, 21]),
+        );
+
+        // Without a budget, every left row is combined with a whole right batch:
+        let join = Arc::new(CrossJoinExec::new(left.clone(), right.clone()));
+        let batches = collect(join, task_ctx.clone()).await?;
+        assert_eq!(batches.len(), 3);
+        assert!(batches.iter().all(|batch| batch.num_rows() == 4));
+
+        // A budget smaller than a single output row still produces one row
+        // per output batch:
+        let join =
+            Arc::new(CrossJoinExec::new(left, right).with_max_output_batch_bytes(1));
+        assert_eq!(join.max_output_batch_bytes(), Some(1));
+        let batches = collect(join, task_ctx).await?;
+        assert_eq!(batches.len(), 12);
+        assert!(batches.iter().all(|batch| batch.num_rows() == 1));
+
+        let expected = [
+            "+----+----+----+----+----+----+",
+            "| a1 | b1 | c1 | a2 | b2 | c2 |",
+            "+----+----+----+----+----+----+",
+            "| 1  | 4  | 7  | 10 | 14 | 18 |",
+            "| 1  | 4  | 7  | 11 | 15 | 19 |",
+            "| 1  | 4  | 7  | 12 | 16 | 20 |",
+            "| 1  | 4  | 7  | 13 | 17 | 21 |",
+            "| 2  | 5  | 8  | 10 | 14 | 18 |",
+            "| 2  | 5  | 8  | 11 | 15 | 19 |",
+            "| 2  | 5  | 8  | 12 | 16 | 20 |",
+            "| 2  | 5  | 8  | 13 | 17 | 21 |",
+            "| 3  | 6  | 9  | 10 | 14 | 18 |",
+            "| 3  | 6  | 9  | 11 | 15 | 19 |",
+            "| 3  | 6  | 9  | 12 | 16 | 20 |",
+            "| 3  | 6  | 9  | 13 | 17 | 21 |",
+            "+----+----+----+----+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_max_output_batch_bytes_with_wide_left_side() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+
+        // 64 rows with 8 columns on the left:
+        let left_schema = Arc::new(Schema::new(
+            (0..8)
+                .map(|i| Field::new(format!("l{i}"), DataType::Int32, false))
+                .collect::<Vec<_>>(),
+        ));
+        let left_batch = RecordBatch::try_new(
+            left_schema.clone(),
+            (0..8)
+                .map(|i| {
+                    Arc::new(Int32Array::from_iter_values(i * 64..(i + 1) * 64)) as _
+                })
+                .collect(),
+        )?;
+        let left = Arc::new(MemoryExec::try_new(&[vec![left_batch]], left_schema, None)?);
+        let right = build_table_scan_i32(
+            ("a2", &(0..16).collect()),
+            ("b2", &(16..32).collect()),
+            ("c2", &(32..48).collect()),
+        );
+
+        // An output row takes 8 * 4 + 3 * 4 = 44 bytes, so the budget fits 46
+        // rows, i.e. two left rows combined with the right batch of 16 rows:
+        let budget = 2048;
+        let join =
+            Arc::new(CrossJoinExec::new(left, right).with_max_output_batch_bytes(budget));
+        let batches = collect(join, task_ctx).await?;
+
+        assert_eq!(batches.len(), 32);
+        for batch in &batches {
+            assert_eq!(batch.num_rows(), 32);
+            assert!(logical_memory_size(batch) <= budget);
+        }
+
+        // Every combination of left and right rows is produced exactly once:
+        let mut pairs = vec![];
+        for batch in &batches {
+            let left = as_int32_array(batch.column(0))?;
+            let right = as_int32_array(batch.column(8))?;
+            pairs.extend(
+                left.values()
+                    .iter()
+                    .copied()
+                    .zip(right.values().iter().copied()),
+            );
+        }
+        pairs.sort_unstable();
+        let expected = (0..64)
+            .flat_map(|l| (0..16).map(move |r| (l, r)))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_max_output_batch_bytes() {
+        let left =
+            build_table_scan_i32(("a1", &vec![1]), ("b1", &vec![2]), ("c1", &vec![3]));
+        let right =
+            build_table_scan_i32(("a2", &vec![4]), ("b2", &vec![5]), ("c2", &vec![6]));
+        let join = CrossJoinExec::new(left, right).with_max_output_batch_bytes(1024);
+
+        let verbose = format!("{}", displayable(&join).indent(true));
+        assert_contains!(verbose, "CrossJoinExec: max_output_batch_bytes=1024");
+
+        // The default format stays concise:
+        let default = format!("{}", displayable(&join).one_line());
+        assert_eq!(default, "CrossJoinExec\n");
+    }
+
     /// Returns the column names on the schema
     fn columns(schema: &Schema) -> Vec<String> {
         schema.fields().iter().map(|f| f.name().clone()).collect()
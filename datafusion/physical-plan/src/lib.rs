@@ -24,6 +24,7 @@ use std::sync::Arc;
 use crate::coalesce_partitions::CoalescePartitionsExec;
 use crate::display::DisplayableExecutionPlan;
 use crate::metrics::MetricsSet;
+use crate::projection::ProjectionExec;
 use crate::repartition::RepartitionExec;
 use crate::sorts::sort_preserving_merge::SortPreservingMergeExec;
 
@@ -402,6 +403,21 @@ pub trait ExecutionPlan: Debug + DisplayAs + Send + Sync {
     fn statistics(&self) -> Result<Statistics> {
         Ok(Statistics::new_unknown(&self.schema()))
     }
+
+    /// If supported, attempt to embed `projection`, whose input is this
+    /// `ExecutionPlan`, into this `ExecutionPlan`. The returned plan must
+    /// produce the same output as `projection`.
+    ///
+    /// If the `ExecutionPlan` can not absorb the projection, returns
+    /// `Ok(None)` (the default). The projection pushdown optimizer rule calls
+    /// this for operators it does not know about, such as custom data sources
+    /// that can read a subset of their columns.
+    fn try_pushdown_projection(
+        &self,
+        _projection: &ProjectionExec,
+    ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        Ok(None)
+    }
 }
 
 /// Extension trait provides an easy API to fetch various properties of